@@ -0,0 +1,103 @@
+//! Compares how many slots (or chain nodes) each hash table inspects to find
+//! a stored key, across key sets that are easy and hard on the hasher.
+//!
+//! Run with `cargo bench --bench probe_lengths`.
+
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+use practice_rust::collections::hash_table_linear_probing::{DefaultBuildHasher, Djb2BuildHasher};
+use practice_rust::collections::map::Map;
+use practice_rust::collections::{
+    hash_table_double_hashing, hash_table_linear_probing, hash_table_quadratic_probing, hash_table_robin_hood,
+    hash_table_separate_chaining,
+};
+
+const KEYS: u64 = 100_000;
+
+// Uses the key itself as the hash, so regular keys collide on the low bits
+#[derive(Default)]
+struct IdentityHasher(u64);
+
+impl Hasher for IdentityHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 << 8) | b as u64;
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = n;
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+type IdentityBuildHasher = BuildHasherDefault<IdentityHasher>;
+
+fn report<M: Map<u64, u64>>(name: &str, mut map: M, keys: &[u64]) {
+    for &key in keys {
+        map.insert(key, key);
+    }
+
+    let mut lengths: Vec<usize> = keys.iter().map(|key| map.probe_length(key).unwrap()).collect();
+    lengths.sort_unstable();
+
+    let mean = lengths.iter().sum::<usize>() as f64 / lengths.len() as f64;
+    let percentile = |p: f64| lengths[((lengths.len() - 1) as f64 * p) as usize];
+
+    println!(
+        "  {:<20} mean {:>7.2}  p50 {:>5}  p90 {:>5}  p99 {:>5}  max {:>6}",
+        name,
+        mean,
+        percentile(0.5),
+        percentile(0.9),
+        percentile(0.99),
+        lengths[lengths.len() - 1],
+    );
+}
+
+fn compare<S: BuildHasher + Clone>(scenario: &str, hash_builder: S, keys: &[u64]) {
+    println!("{} ({} keys)", scenario, keys.len());
+
+    report(
+        "linear probing",
+        hash_table_linear_probing::HashTable::with_hasher(hash_builder.clone()),
+        keys,
+    );
+    report(
+        "quadratic probing",
+        hash_table_quadratic_probing::HashTable::with_hasher(hash_builder.clone()),
+        keys,
+    );
+    report(
+        "double hashing",
+        hash_table_double_hashing::HashTable::with_hasher(hash_builder.clone()),
+        keys,
+    );
+    report(
+        "robin hood",
+        hash_table_robin_hood::HashTable::with_hasher(hash_builder.clone()),
+        keys,
+    );
+    report(
+        "separate chaining",
+        hash_table_separate_chaining::HashTable::with_hasher(hash_builder),
+        keys,
+    );
+
+    println!();
+}
+
+fn main() {
+    let sequential: Vec<u64> = (0..KEYS).collect();
+    let strided: Vec<u64> = (0..KEYS).map(|i| i * 64).collect();
+    let clustered: Vec<u64> = (0..KEYS).map(|i| (i / 16) * 4096 + i % 16).collect();
+
+    compare("sequential keys, SipHash", DefaultBuildHasher::default(), &sequential);
+    compare("sequential keys, djb2", Djb2BuildHasher::default(), &sequential);
+    compare("sequential keys, identity", IdentityBuildHasher::default(), &sequential);
+    compare("runs of 16 keys, identity", IdentityBuildHasher::default(), &clustered);
+    compare("stride 64 keys, identity", IdentityBuildHasher::default(), &strided);
+}
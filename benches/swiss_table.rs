@@ -0,0 +1,106 @@
+//! Times the Swiss table against the linear probing table and std's HashMap
+//! on inserts, successful and failed lookups, and removals.
+//!
+//! Run with `cargo bench --bench swiss_table`, optionally with `--features sse2`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use practice_rust::collections::map::Map;
+use practice_rust::collections::{hash_table_linear_probing, hash_table_swiss};
+
+const SIZES: [u64; 3] = [1_000, 100_000, 1_000_000];
+
+// Lets std's HashMap run through the same measurements as the crate's tables
+struct StdHashMap(HashMap<u64, u64>);
+
+impl Map<u64, u64> for StdHashMap {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn insert(&mut self, key: u64, value: u64) -> Option<u64> {
+        self.0.insert(key, value)
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&u64>
+    where
+        u64: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.0.get(key)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut u64>
+    where
+        u64: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<u64>
+    where
+        u64: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        self.0.remove(key)
+    }
+
+    fn probe_length<Q>(&self, _key: &Q) -> Option<usize>
+    where
+        u64: std::borrow::Borrow<Q>,
+        Q: std::hash::Hash + Eq + ?Sized,
+    {
+        None
+    }
+}
+
+fn per_op(elapsed: Duration, ops: u64) -> f64 {
+    elapsed.as_nanos() as f64 / ops as f64
+}
+
+fn bench<M: Map<u64, u64>>(name: &str, mut map: M, size: u64) {
+    // Spread the keys over the whole u64 range so they aren't trivially sequential
+    let keys: Vec<u64> = (0..size).map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15)).collect();
+
+    let start = Instant::now();
+    for &key in &keys {
+        map.insert(key, key);
+    }
+    let insert = per_op(start.elapsed(), size);
+
+    let start = Instant::now();
+    for key in &keys {
+        black_box(map.get(key));
+    }
+    let hit = per_op(start.elapsed(), size);
+
+    let start = Instant::now();
+    for key in &keys {
+        black_box(map.get(&(key + 1)));
+    }
+    let miss = per_op(start.elapsed(), size);
+
+    let start = Instant::now();
+    for key in &keys {
+        black_box(map.remove(key));
+    }
+    let remove = per_op(start.elapsed(), size);
+
+    println!(
+        "  {:<16} insert {:>7.1} ns  hit {:>7.1} ns  miss {:>7.1} ns  remove {:>7.1} ns",
+        name, insert, hit, miss, remove
+    );
+}
+
+fn main() {
+    for size in SIZES {
+        println!("{} keys", size);
+        bench("linear probing", hash_table_linear_probing::HashTable::new(), size);
+        bench("swiss table", hash_table_swiss::HashTable::new(), size);
+        bench("std HashMap", StdHashMap(HashMap::new()), size);
+        println!();
+    }
+}
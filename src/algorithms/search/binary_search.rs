@@ -0,0 +1,48 @@
+pub fn binary_search(arr: &[usize], target: usize) -> Option<usize> {
+    // Searches `low..high`, so an empty slice never enters the loop
+    let mut low = 0;
+    let mut high = arr.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let guess = arr[mid];
+
+        if guess == target {
+            return Some(mid);
+        } else if guess > target {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::binary_search;
+
+    #[test]
+    fn test_binary_search() {
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        for i in 1..10 {
+            assert_eq!(binary_search(&arr, i), Some(i - 1));
+        }
+    }
+
+    #[test]
+    fn test_binary_search_empty() {
+        assert_eq!(binary_search(&[], 1), None);
+    }
+
+    #[test]
+    fn test_binary_search_missing() {
+        let arr = [1, 3, 5];
+
+        assert_eq!(binary_search(&arr, 0), None);
+        assert_eq!(binary_search(&arr, 4), None);
+        assert_eq!(binary_search(&arr, 6), None);
+    }
+}
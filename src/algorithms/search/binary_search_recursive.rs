@@ -0,0 +1,45 @@
+/// Searches `arr[low..high]`, so the whole slice is `0, arr.len()`.
+pub fn binary_search(arr: &[usize], target: &usize, low: usize, high: usize) -> Option<usize> {
+    if low >= high {
+        return None;
+    }
+
+    let mid = low + (high - low) / 2;
+    let guess = &arr[mid];
+
+    if guess > target {
+        return binary_search(arr, target, low, mid);
+    } else if guess < target {
+        return binary_search(arr, target, mid + 1, high);
+    } 
+
+    Some(mid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::binary_search;
+
+    #[test]
+    fn test_binary_search() {
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        for i in 1..10 {
+            assert_eq!(binary_search(&arr, &i, 0, arr.len()), Some(i - 1));
+        }
+    }
+
+    #[test]
+    fn test_binary_search_empty() {
+        assert_eq!(binary_search(&[], &1, 0, 0), None);
+    }
+
+    #[test]
+    fn test_binary_search_missing() {
+        let arr = [1, 3, 5];
+
+        assert_eq!(binary_search(&arr, &0, 0, arr.len()), None);
+        assert_eq!(binary_search(&arr, &4, 0, arr.len()), None);
+        assert_eq!(binary_search(&arr, &6, 0, arr.len()), None);
+    }
+}
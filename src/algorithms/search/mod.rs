@@ -0,0 +1,5 @@
+pub mod binary_search;
+pub mod binary_search_recursive;
+
+pub use binary_search::binary_search;
+pub use binary_search_recursive::binary_search as binary_search_recursive;
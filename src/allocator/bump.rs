@@ -0,0 +1,185 @@
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+use super::{AllocError, Allocator, Global};
+
+/// An arena that hands out memory by bumping an offset into one fixed chunk.
+///
+/// Individual deallocations are free, and only the most recent block can be
+/// given back or resized in place. Everything else is reclaimed at once by
+/// `reset` or when the arena is dropped.
+pub struct BumpAllocator {
+    start: NonNull<u8>,
+    layout: Layout,
+    offset: Cell<usize>,
+}
+
+impl BumpAllocator {
+    const CHUNK_ALIGN: usize = 16;
+
+    pub fn new(capacity: usize) -> Self {
+        let layout = Layout::from_size_align(capacity.max(1), Self::CHUNK_ALIGN).expect("capacity overflow");
+        let start = match Global.allocate(layout) {
+            Ok(ptr) => ptr,
+            Err(_) => std::alloc::handle_alloc_error(layout),
+        };
+
+        Self {
+            start,
+            layout,
+            offset: Cell::new(0),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.layout.size()
+    }
+
+    pub fn used(&self) -> usize {
+        self.offset.get()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.used()
+    }
+
+    /// Reclaims every block at once. Taking `&mut self` guarantees no
+    /// collection is still borrowing the arena.
+    pub fn reset(&mut self) {
+        self.offset.set(0);
+    }
+
+    fn end_of(&self, ptr: NonNull<u8>, size: usize) -> usize {
+        ptr.as_ptr() as usize - self.start.as_ptr() as usize + size
+    }
+
+    fn is_last(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        self.end_of(ptr, layout.size()) == self.offset.get()
+    }
+}
+
+unsafe impl Allocator for BumpAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let base = self.start.as_ptr() as usize;
+        let current = base + self.offset.get();
+        let aligned = current.checked_add(layout.align() - 1).ok_or(AllocError)? & !(layout.align() - 1);
+
+        let begin = aligned - base;
+        let end = begin.checked_add(layout.size()).ok_or(AllocError)?;
+        if end > self.capacity() {
+            return Err(AllocError);
+        }
+
+        self.offset.set(end);
+        // Offsetting from `start` keeps the pointer's provenance on the chunk
+        Ok(unsafe { NonNull::new_unchecked(self.start.as_ptr().add(begin)) })
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if self.is_last(ptr, layout) {
+            self.offset.set(self.offset.get() - layout.size());
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        if self.is_last(ptr, old_layout) {
+            let end = self.end_of(ptr, new_layout.size());
+            if end <= self.capacity() {
+                self.offset.set(end);
+                return Ok(ptr);
+            }
+        }
+
+        let new_ptr = self.allocate(new_layout)?;
+        std::ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+        Ok(new_ptr)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        if self.is_last(ptr, old_layout) {
+            self.offset.set(self.end_of(ptr, new_layout.size()));
+        }
+
+        Ok(ptr)
+    }
+}
+
+impl Drop for BumpAllocator {
+    fn drop(&mut self) {
+        unsafe { Global.deallocate(self.start, self.layout) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate_respects_alignment() {
+        let arena = BumpAllocator::new(64);
+
+        let a = arena.allocate(Layout::new::<u8>()).unwrap();
+        let b = arena.allocate(Layout::new::<u64>()).unwrap();
+
+        assert_eq!(b.as_ptr() as usize % 8, 0);
+        assert!(b.as_ptr() as usize > a.as_ptr() as usize);
+        assert_eq!(arena.used(), 16);
+    }
+
+    #[test]
+    fn allocate_fails_when_full() {
+        let arena = BumpAllocator::new(16);
+
+        assert!(arena.allocate(Layout::new::<[u8; 16]>()).is_ok());
+        assert_eq!(arena.allocate(Layout::new::<u8>()), Err(AllocError));
+    }
+
+    #[test]
+    fn deallocate_only_reclaims_last_block() {
+        let arena = BumpAllocator::new(64);
+        let layout = Layout::new::<u32>();
+
+        let a = arena.allocate(layout).unwrap();
+        let b = arena.allocate(layout).unwrap();
+
+        unsafe { arena.deallocate(a, layout) };
+        assert_eq!(arena.used(), 8);
+
+        unsafe { arena.deallocate(b, layout) };
+        assert_eq!(arena.used(), 4);
+    }
+
+    #[test]
+    fn grow_last_block_in_place() {
+        let arena = BumpAllocator::new(64);
+        let old = Layout::array::<u32>(2).unwrap();
+        let new = Layout::array::<u32>(4).unwrap();
+
+        let ptr = arena.allocate(old).unwrap();
+        let grown = unsafe { arena.grow(ptr, old, new) }.unwrap();
+
+        assert_eq!(ptr, grown);
+        assert_eq!(arena.used(), 16);
+    }
+
+    #[test]
+    fn reset() {
+        let mut arena = BumpAllocator::new(32);
+        arena.allocate(Layout::new::<[u8; 32]>()).unwrap();
+        assert_eq!(arena.remaining(), 0);
+
+        arena.reset();
+        assert_eq!(arena.remaining(), 32);
+    }
+}
@@ -0,0 +1,139 @@
+use std::alloc::Layout;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{AllocError, Allocator, Global};
+
+/// Wraps another allocator and keeps statistics about the calls made to it,
+/// which makes it easy to assert that a collection frees everything it takes.
+#[derive(Debug, Default)]
+pub struct CountingAllocator<A = Global> {
+    inner: A,
+    allocations: AtomicUsize,
+    deallocations: AtomicUsize,
+    bytes_in_use: AtomicUsize,
+    peak_bytes: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<A> CountingAllocator<A> {
+    pub fn new_in(inner: A) -> Self {
+        Self {
+            inner,
+            allocations: AtomicUsize::new(0),
+            deallocations: AtomicUsize::new(0),
+            bytes_in_use: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn allocations(&self) -> usize {
+        self.allocations.load(Ordering::Relaxed)
+    }
+
+    pub fn deallocations(&self) -> usize {
+        self.deallocations.load(Ordering::Relaxed)
+    }
+
+    pub fn live_allocations(&self) -> usize {
+        self.allocations() - self.deallocations()
+    }
+
+    pub fn bytes_in_use(&self) -> usize {
+        self.bytes_in_use.load(Ordering::Relaxed)
+    }
+
+    pub fn peak_bytes(&self) -> usize {
+        self.peak_bytes.load(Ordering::Relaxed)
+    }
+
+    fn track_alloc(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        let in_use = self.bytes_in_use.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes.fetch_max(in_use, Ordering::Relaxed);
+    }
+
+    fn track_dealloc(&self, size: usize) {
+        self.deallocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes_in_use.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+// A resize counts as freeing the old block and allocating the new one
+unsafe impl<A: Allocator> Allocator for CountingAllocator<A> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let ptr = self.inner.allocate(layout)?;
+        self.track_alloc(layout.size());
+        Ok(ptr)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.inner.deallocate(ptr, layout);
+        self.track_dealloc(layout.size());
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = self.inner.grow(ptr, old_layout, new_layout)?;
+        self.track_dealloc(old_layout.size());
+        self.track_alloc(new_layout.size());
+        Ok(new_ptr)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = self.inner.shrink(ptr, old_layout, new_layout)?;
+        self.track_dealloc(old_layout.size());
+        self.track_alloc(new_layout.size());
+        Ok(new_ptr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let counter = CountingAllocator::new();
+        let layout = Layout::new::<u64>();
+
+        let ptr = counter.allocate(layout).unwrap();
+        assert_eq!(counter.allocations(), 1);
+        assert_eq!(counter.bytes_in_use(), 8);
+
+        unsafe { counter.deallocate(ptr, layout) };
+        assert_eq!(counter.deallocations(), 1);
+        assert_eq!(counter.live_allocations(), 0);
+        assert_eq!(counter.bytes_in_use(), 0);
+        assert_eq!(counter.peak_bytes(), 8);
+    }
+
+    #[test]
+    fn counts_resizes() {
+        let counter = CountingAllocator::new();
+        let old = Layout::array::<u64>(1).unwrap();
+        let new = Layout::array::<u64>(4).unwrap();
+
+        let ptr = counter.allocate(old).unwrap();
+        let ptr = unsafe { counter.grow(ptr, old, new) }.unwrap();
+        assert_eq!(counter.bytes_in_use(), 32);
+        assert_eq!(counter.live_allocations(), 1);
+
+        unsafe { counter.deallocate(ptr, new) };
+        assert_eq!(counter.live_allocations(), 0);
+    }
+}
@@ -0,0 +1,189 @@
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::ptr::{self, NonNull};
+
+pub mod bump;
+pub mod counting;
+
+pub use bump::BumpAllocator;
+pub use counting::CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+impl std::error::Error for AllocError {}
+
+/// A source of memory for the collections in this crate.
+///
+/// This mirrors the unstable `std::alloc::Allocator` closely enough to be
+/// swapped for it later, but only relies on stable Rust. Collections never
+/// request zero-sized layouts, so implementations don't need to handle them.
+///
+/// # Safety
+///
+/// A block returned by `allocate`, `grow` or `shrink` must be valid for reads
+/// and writes of its layout and must not overlap any other live block until it
+/// is handed back to `deallocate`, `grow` or `shrink`. Moving the allocator
+/// must not invalidate its blocks.
+///
+/// If the allocator implements `Clone`, a clone must stand for the same
+/// allocator: blocks from either can be resized or freed through the other,
+/// and stay valid for as long as any of them is alive.
+///
+/// `is_interchangeable` must only return `true` if the same holds for `self`
+/// and `other`, even after one of them is dropped.
+pub unsafe trait Allocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// Whether blocks from `other` can be handed over to `self`, which
+    /// collections rely on to move nodes between each other without copying.
+    /// Two distinct arenas never can, so this defaults to `false`.
+    fn is_interchangeable(&self, _other: &Self) -> bool {
+        false
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this allocator for `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this allocator for `old_layout`, and
+    /// `new_layout` must be at least as large with the same alignment.
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new_ptr)
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this allocator for `old_layout`, and
+    /// `new_layout` must be at most as large with the same alignment.
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = self.allocate(new_layout)?;
+        ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), new_layout.size());
+        self.deallocate(ptr, old_layout);
+        Ok(new_ptr)
+    }
+}
+
+// Lets a collection borrow an allocator that outlives it, e.g. an arena
+unsafe impl<A: Allocator + ?Sized> Allocator for &A {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        (**self).allocate(layout)
+    }
+
+    fn is_interchangeable(&self, other: &Self) -> bool {
+        ptr::eq(*self, *other) || (**self).is_interchangeable(*other)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        (**self).deallocate(ptr, layout)
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        (**self).grow(ptr, old_layout, new_layout)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        (**self).shrink(ptr, old_layout, new_layout)
+    }
+}
+
+/// The global allocator, used by every collection unless told otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        NonNull::new(unsafe { alloc::alloc(layout) }).ok_or(AllocError)
+    }
+
+    fn is_interchangeable(&self, _other: &Self) -> bool {
+        true
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        alloc::dealloc(ptr.as_ptr(), layout)
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        NonNull::new(alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size())).ok_or(AllocError)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        NonNull::new(alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size())).ok_or(AllocError)
+    }
+}
+
+/// Moves `value` into a fresh block from `alloc`, like `Box::new_in`.
+pub(crate) fn box_in<T, A: Allocator>(value: T, alloc: &A) -> NonNull<T> {
+    let layout = Layout::new::<T>();
+    if layout.size() == 0 {
+        return NonNull::dangling();
+    }
+
+    let ptr = match alloc.allocate(layout) {
+        Ok(ptr) => ptr.cast::<T>(),
+        Err(_) => alloc::handle_alloc_error(layout),
+    };
+
+    unsafe { ptr.as_ptr().write(value) };
+    ptr
+}
+
+/// Moves the value out of a block created by `box_in` and frees the block.
+///
+/// # Safety
+///
+/// `ptr` must come from `box_in` with the same allocator and must not be used
+/// afterwards.
+pub(crate) unsafe fn unbox_in<T, A: Allocator>(ptr: NonNull<T>, alloc: &A) -> T {
+    let value = ptr.as_ptr().read();
+
+    let layout = Layout::new::<T>();
+    if layout.size() != 0 {
+        alloc.deallocate(ptr.cast(), layout);
+    }
+
+    value
+}
@@ -0,0 +1,731 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+use std::ptr;
+
+use super::raw_buffer::RawBuffer;
+use crate::allocator::{Allocator, Global};
+
+/// A double-ended queue stored in a growable ring buffer. The elements run
+/// from `head` to the end of the buffer and, once that fills up, wrap around
+/// to its start, so both ends can be pushed and popped in O(1).
+pub struct Deque<T, A: Allocator = Global> {
+    buf: RawBuffer<T, A>,
+    // Buffer slot of the front element
+    head: usize,
+    len: usize,
+}
+
+impl<T> Deque<T> {
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> Deque<T, A> {
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            buf: RawBuffer::new_in(alloc),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let mut deque = Self::new_in(alloc);
+        if capacity > deque.capacity() {
+            deque.reallocate(capacity);
+        }
+        deque
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.buf.allocator()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Buffer slot `offset` places after `slot`, wrapping around the end
+    fn wrap_add(&self, slot: usize, offset: usize) -> usize {
+        let (sum, overflowed) = slot.overflowing_add(offset);
+        if overflowed || sum >= self.buf.capacity() {
+            sum.wrapping_sub(self.buf.capacity())
+        } else {
+            sum
+        }
+    }
+
+    fn wrap_sub(&self, slot: usize, offset: usize) -> usize {
+        if slot >= offset {
+            slot - offset
+        } else {
+            slot + (self.buf.capacity() - offset)
+        }
+    }
+
+    // Buffer slot holding the element at `index`
+    fn slot(&self, index: usize) -> usize {
+        self.wrap_add(self.head, index)
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        if self.len == self.buf.capacity() {
+            self.grow();
+        }
+
+        let slot = self.slot(self.len);
+        unsafe { ptr::write(self.buf.ptr().add(slot), value) };
+        self.len += 1;
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        if self.len == self.buf.capacity() {
+            self.grow();
+        }
+
+        self.head = self.wrap_sub(self.head, 1);
+        unsafe { ptr::write(self.buf.ptr().add(self.head), value) };
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let value = unsafe { ptr::read(self.buf.ptr().add(self.head)) };
+        self.head = self.wrap_add(self.head, 1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        Some(unsafe { ptr::read(self.buf.ptr().add(slot)) })
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { &*self.buf.ptr().add(self.slot(index)) })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { &mut *self.buf.ptr().add(self.slot(index)) })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.len.checked_sub(1).and_then(move |index| self.get_mut(index))
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        self.head = 0;
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.head <= self.buf.capacity() - self.len
+    }
+
+    /// The elements in order, split where the ring wraps around. The second
+    /// slice is empty unless the elements wrap.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr = self.buf.ptr();
+
+        unsafe {
+            if self.is_contiguous() {
+                (std::slice::from_raw_parts(ptr.add(self.head), self.len), &[])
+            } else {
+                let front = self.buf.capacity() - self.head;
+                (
+                    std::slice::from_raw_parts(ptr.add(self.head), front),
+                    std::slice::from_raw_parts(ptr, self.len - front),
+                )
+            }
+        }
+    }
+
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let ptr = self.buf.ptr();
+
+        unsafe {
+            if self.is_contiguous() {
+                (std::slice::from_raw_parts_mut(ptr.add(self.head), self.len), &mut [])
+            } else {
+                let front = self.buf.capacity() - self.head;
+                (
+                    std::slice::from_raw_parts_mut(ptr.add(self.head), front),
+                    std::slice::from_raw_parts_mut(ptr, self.len - front),
+                )
+            }
+        }
+    }
+
+    /// Moves the elements so they no longer wrap and returns them as one
+    /// slice.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if !self.is_contiguous() {
+            let ptr = self.buf.ptr();
+            let front = self.buf.capacity() - self.head;
+            let back = self.len - front;
+
+            unsafe {
+                // Closing the gap leaves the back part first and the front
+                // part after it, which a rotation then swaps
+                ptr::copy(ptr.add(self.head), ptr.add(back), front);
+                std::slice::from_raw_parts_mut(ptr, self.len).rotate_left(back);
+            }
+            self.head = 0;
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(self.buf.ptr().add(self.head), self.len) }
+    }
+
+    /// Rotates the elements `n` places to the left, so the element at `n`
+    /// becomes the front.
+    pub fn rotate_left(&mut self, n: usize) {
+        if n > self.len {
+            panic!("Rotation ({}) is out of bounds len ({})", n, self.len);
+        }
+
+        if self.len == self.buf.capacity() {
+            // A full ring has no gap to move elements across, so moving
+            // where it starts is enough
+            self.head = self.wrap_add(self.head, n);
+        } else if n <= self.len / 2 {
+            for _ in 0..n {
+                let value = self.pop_front().unwrap();
+                self.push_back(value);
+            }
+        } else {
+            for _ in 0..self.len - n {
+                let value = self.pop_back().unwrap();
+                self.push_front(value);
+            }
+        }
+    }
+
+    /// Rotates the elements `n` places to the right, so the element at
+    /// `len - n` becomes the front.
+    pub fn rotate_right(&mut self, n: usize) {
+        if n > self.len {
+            panic!("Rotation ({}) is out of bounds len ({})", n, self.len);
+        }
+
+        self.rotate_left(self.len - n);
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.buf.capacity() {
+            let doubled = self.buf.capacity().saturating_mul(2);
+            self.reallocate(required.max(doubled));
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut {
+            front: front.iter_mut(),
+            back: back.iter_mut(),
+        }
+    }
+
+    fn grow(&mut self) {
+        let old_capacity = self.buf.capacity();
+        self.buf.grow(4);
+        self.unwrap_ring(old_capacity);
+    }
+
+    // Moves the buffer to an allocation of `new_capacity` elements, at least
+    // double the old one
+    fn reallocate(&mut self, new_capacity: usize) {
+        debug_assert!(new_capacity >= self.buf.capacity().saturating_mul(2) && new_capacity > 0);
+
+        let old_capacity = self.buf.capacity();
+        self.buf.reallocate(new_capacity);
+        self.unwrap_ring(old_capacity);
+    }
+
+    // The allocator keeps the old slots in place when the buffer grows, so a
+    // ring that wrapped is unwrapped by moving its wrapped part to just past
+    // the old end, where doubling guarantees there is room
+    fn unwrap_ring(&mut self, old_capacity: usize) {
+        if self.head > old_capacity - self.len {
+            let wrapped = self.len - (old_capacity - self.head);
+            unsafe { ptr::copy_nonoverlapping(self.buf.ptr(), self.buf.ptr().add(old_capacity), wrapped) };
+        }
+    }
+}
+
+// Deque owns its elements just like Vector does, so it is as thread-safe as T
+// and the allocator it carries
+unsafe impl<T: Send, A: Allocator + Send> Send for Deque<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Deque<T, A> {}
+
+impl<T, A: Allocator> Drop for Deque<T, A> {
+    fn drop(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for Deque<T, A> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("Index ({}) is out of bounds len ({})", index, self.len),
+        }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for Deque<T, A> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("Index ({}) is out of bounds len ({})", index, len),
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    front: std::slice::Iter<'a, T>,
+    back: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    front: std::slice::IterMut<'a, T>,
+    back: std::slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    deque: Deque<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for Deque<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a Deque<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Deque<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Deque<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for Deque<T, A> {
+    fn clone(&self) -> Self {
+        let mut deque = Self::with_capacity_in(self.len, self.buf.allocator().clone());
+        deque.extend(self.iter().cloned());
+        deque
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Deque<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for Deque<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for Deque<T, A> {}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_vec<T: Clone, A: Allocator>(deque: &Deque<T, A>) -> Vec<T> {
+        deque.iter().cloned().collect()
+    }
+
+    // Builds a deque whose elements wrap around the end of the buffer
+    fn wrapped() -> Deque<i32> {
+        let mut deque = Deque::with_capacity(8);
+        for i in 0..6 {
+            deque.push_back(i);
+        }
+        for _ in 0..4 {
+            deque.pop_front();
+        }
+        for i in 6..10 {
+            deque.push_back(i);
+        }
+        deque
+    }
+
+    #[test]
+    fn push_and_pop_both_ends() {
+        let mut deque = Deque::new();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        deque.push_front(0);
+
+        assert_eq!(to_vec(&deque), vec![0, 1, 2, 3]);
+        assert_eq!(deque.front(), Some(&0));
+        assert_eq!(deque.back(), Some(&3));
+
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(0));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert_eq!(deque.pop_back(), None);
+        assert_eq!(deque.pop_front(), None);
+    }
+
+    #[test]
+    fn index() {
+        let mut deque = wrapped();
+        assert_eq!(deque[0], 4);
+        assert_eq!(deque[5], 9);
+
+        deque[5] = 90;
+        assert_eq!(deque.back(), Some(&90));
+        assert_eq!(deque.get(6), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (6) is out of bounds len (6)")]
+    fn index_out_of_bounds() {
+        let deque = wrapped();
+        let _ = deque[6];
+    }
+
+    #[test]
+    fn as_slices() {
+        let deque = wrapped();
+        assert_eq!(deque.as_slices(), (&[4, 5, 6, 7][..], &[8, 9][..]));
+
+        let contiguous: Deque<i32> = (0..3).collect();
+        assert_eq!(contiguous.as_slices(), (&[0, 1, 2][..], &[][..]));
+    }
+
+    #[test]
+    fn make_contiguous() {
+        let mut deque = wrapped();
+        assert_eq!(deque.make_contiguous(), &[4, 5, 6, 7, 8, 9]);
+        assert_eq!(deque.as_slices(), (&[4, 5, 6, 7, 8, 9][..], &[][..]));
+
+        deque.push_front(3);
+        deque.push_back(10);
+        assert_eq!(to_vec(&deque), (3..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn make_contiguous_with_a_long_wrapped_part() {
+        let mut deque = Deque::with_capacity(8);
+        for i in 0..8 {
+            deque.push_back(i);
+        }
+        for _ in 0..6 {
+            deque.pop_front();
+        }
+        for i in 8..13 {
+            deque.push_back(i);
+        }
+
+        assert_eq!(deque.as_slices().1.len(), 5);
+        assert_eq!(deque.make_contiguous(), &[6, 7, 8, 9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn growth_unwraps_the_ring() {
+        let mut deque = wrapped();
+        deque.push_back(10);
+        deque.push_back(11);
+        assert_eq!(deque.capacity(), 8);
+
+        deque.push_back(12);
+        assert_eq!(deque.capacity(), 16);
+        assert_eq!(deque.as_slices(), (&[4, 5, 6, 7, 8, 9, 10, 11, 12][..], &[][..]));
+    }
+
+    #[test]
+    fn growth_from_push_front() {
+        let mut deque = Deque::new();
+        for i in 0..100 {
+            deque.push_front(i);
+        }
+
+        assert_eq!(to_vec(&deque), (0..100).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reserve_on_a_wrapped_ring() {
+        let mut deque = wrapped();
+        deque.reserve(3);
+        assert!(deque.capacity() >= 9);
+        assert_eq!(to_vec(&deque), (4..10).collect::<Vec<_>>());
+
+        let mut deque = wrapped();
+        deque.reserve(100);
+        assert_eq!(to_vec(&deque), (4..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn rotate() {
+        let mut deque: Deque<i32> = (0..10).collect();
+
+        deque.rotate_left(3);
+        assert_eq!(to_vec(&deque), vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+        deque.rotate_right(3);
+        assert_eq!(to_vec(&deque), (0..10).collect::<Vec<_>>());
+        deque.rotate_left(8);
+        assert_eq!(to_vec(&deque), vec![8, 9, 0, 1, 2, 3, 4, 5, 6, 7]);
+        deque.rotate_right(0);
+        deque.rotate_left(10);
+        assert_eq!(to_vec(&deque), vec![8, 9, 0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn rotate_full_ring() {
+        let mut deque = Deque::with_capacity(4);
+        deque.extend(0..4);
+        assert_eq!(deque.len(), deque.capacity());
+
+        deque.rotate_left(1);
+        assert_eq!(to_vec(&deque), vec![1, 2, 3, 0]);
+        deque.rotate_right(2);
+        assert_eq!(to_vec(&deque), vec![3, 0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rotation (4) is out of bounds len (3)")]
+    fn rotate_out_of_bounds() {
+        let mut deque: Deque<i32> = (0..3).collect();
+        deque.rotate_left(4);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut deque = wrapped();
+
+        for value in &mut deque {
+            *value *= 10;
+        }
+        assert_eq!(deque.iter().rev().copied().collect::<Vec<_>>(), vec![90, 80, 70, 60, 50, 40]);
+        assert_eq!(deque.iter().len(), 6);
+
+        let mut owned = deque.into_iter();
+        assert_eq!(owned.next(), Some(40));
+        assert_eq!(owned.next_back(), Some(90));
+        assert_eq!(owned.collect::<Vec<_>>(), vec![50, 60, 70, 80]);
+    }
+
+    #[test]
+    fn clone_eq_and_debug() {
+        let deque = wrapped();
+        let copy = deque.clone();
+
+        assert_eq!(deque, copy);
+        assert_eq!(format!("{:?}", copy), "[4, 5, 6, 7, 8, 9]");
+    }
+
+    #[test]
+    fn drops_every_element() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut deque = Deque::with_capacity(4);
+        for _ in 0..3 {
+            deque.push_back(Rc::clone(&value));
+        }
+        deque.pop_front();
+        for _ in 0..3 {
+            deque.push_back(Rc::clone(&value));
+        }
+        assert_eq!(Rc::strong_count(&value), 6);
+
+        drop(deque);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut deque = Deque::new();
+        for _ in 0..10 {
+            deque.push_front(());
+            deque.push_back(());
+        }
+
+        assert_eq!(deque.len(), 20);
+        assert_eq!(deque.iter().count(), 20);
+        assert_eq!(deque.pop_front(), Some(()));
+        assert_eq!(deque.pop_back(), Some(()));
+        assert_eq!(deque.len(), 18);
+    }
+
+    #[test]
+    fn counting_allocator() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut deque = Deque::new_in(&counter);
+        for i in 0..100 {
+            deque.push_back(i);
+            deque.push_front(i);
+        }
+        assert_eq!(counter.live_allocations(), 1);
+
+        drop(deque);
+        assert_eq!(counter.live_allocations(), 0);
+    }
+}
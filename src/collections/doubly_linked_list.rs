@@ -0,0 +1,802 @@
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::allocator::{box_in, unbox_in, Allocator, Global};
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    prev: Link<T>,
+    next: Link<T>,
+}
+
+/// A linked list whose nodes point both ways, so either end can be pushed
+/// and popped in O(1). Cursors walk the list in both directions and edit
+/// it at their position without rewalking from the head.
+pub struct DoublyLinkedList<T, A: Allocator = Global> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    alloc: A,
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> DoublyLinkedList<T> {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> DoublyLinkedList<T, A> {
+    pub fn new_in(alloc: A) -> DoublyLinkedList<T, A> {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // Links a new node between two adjacent nodes, where `None` stands for
+    // the end of the list on that side
+    fn link_between(&mut self, prev: Link<T>, next: Link<T>, value: T) -> NonNull<Node<T>> {
+        let node = box_in(Node { value, prev, next }, &self.alloc);
+
+        unsafe {
+            match prev {
+                Some(mut prev) => prev.as_mut().next = Some(node),
+                None => self.head = Some(node),
+            }
+            match next {
+                Some(mut next) => next.as_mut().prev = Some(node),
+                None => self.tail = Some(node),
+            }
+        }
+        self.len += 1;
+        node
+    }
+
+    // Unlinks `node` and frees it. The node must belong to this list.
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        let node = unbox_in(node, &self.alloc);
+
+        match node.prev {
+            Some(mut prev) => prev.as_mut().next = node.next,
+            None => self.head = node.next,
+        }
+        match node.next {
+            Some(mut next) => next.as_mut().prev = node.prev,
+            None => self.tail = node.prev,
+        }
+        self.len -= 1;
+
+        node.value
+    }
+
+    // Moves every element of `other` in between two adjacent nodes of this
+    // list. Nodes from an allocator this one can't free are not adopted, their
+    // values are moved into new nodes instead.
+    fn splice_between(&mut self, mut prev: Link<T>, next: Link<T>, mut other: Self) {
+        if !self.alloc.is_interchangeable(&other.alloc) {
+            while let Some(value) = other.pop_front() {
+                prev = Some(self.link_between(prev, next, value));
+            }
+            return;
+        }
+
+        let (Some(mut first), Some(mut last)) = (other.head.take(), other.tail.take()) else {
+            return;
+        };
+
+        unsafe {
+            first.as_mut().prev = prev;
+            last.as_mut().next = next;
+            match prev {
+                Some(mut prev) => prev.as_mut().next = Some(first),
+                None => self.head = Some(first),
+            }
+            match next {
+                Some(mut next) => next.as_mut().prev = Some(last),
+                None => self.tail = Some(last),
+            }
+        }
+        self.len += std::mem::take(&mut other.len);
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.link_between(None, self.head, value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.link_between(self.tail, None, value);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|node| unsafe { self.unlink(node) })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|node| unsafe { self.unlink(node) })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T, A> {
+        Cursor {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+}
+
+/// A read-only position in a `DoublyLinkedList`. Besides the elements, a
+/// cursor can rest on a "ghost" position past the back and before the front,
+/// so moving past either end wraps around through it.
+pub struct Cursor<'a, T, A: Allocator = Global> {
+    current: Link<T>,
+    // Equals the list's length on the ghost position
+    index: usize,
+    list: &'a DoublyLinkedList<T, A>,
+}
+
+impl<'a, T, A: Allocator> Cursor<'a, T, A> {
+    /// The position of the current element, or `None` on the ghost.
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().next };
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().prev };
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        };
+        next.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        };
+        prev.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+}
+
+/// A position in a `DoublyLinkedList` that can also edit the list around it.
+/// See `Cursor` for how the ghost position works.
+pub struct CursorMut<'a, T, A: Allocator = Global> {
+    current: Link<T>,
+    index: usize,
+    list: &'a mut DoublyLinkedList<T, A>,
+}
+
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().next };
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().prev };
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.next_node().map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        self.prev_node().map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    // The nodes on either side of the cursor, where the ghost sits between
+    // the tail and the head
+    fn next_node(&self) -> Link<T> {
+        match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        }
+    }
+
+    fn prev_node(&self) -> Link<T> {
+        match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        }
+    }
+
+    /// Inserts before the current element, or at the back on the ghost.
+    pub fn insert_before(&mut self, value: T) {
+        let prev = self.prev_node();
+        self.list.link_between(prev, self.current, value);
+        self.index += 1;
+    }
+
+    /// Inserts after the current element, or at the front on the ghost.
+    pub fn insert_after(&mut self, value: T) {
+        let next = self.next_node();
+        self.list.link_between(self.current, next, value);
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Removes the current element and moves on to the one after it.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        self.current = unsafe { node.as_ref().next };
+        Some(unsafe { self.list.unlink(node) })
+    }
+
+    /// Moves the elements of `other` in before the current element, or to
+    /// the back on the ghost. The nodes are relinked rather than copied when
+    /// the two allocators are interchangeable.
+    pub fn splice_before(&mut self, other: DoublyLinkedList<T, A>) {
+        let prev = self.prev_node();
+        let added = other.len;
+        self.list.splice_between(prev, self.current, other);
+        self.index += added;
+    }
+
+    /// Moves the elements of `other` in after the current element, or to the
+    /// front on the ghost.
+    pub fn splice_after(&mut self, other: DoublyLinkedList<T, A>) {
+        let next = self.next_node();
+        let added = other.len;
+        self.list.splice_between(self.current, next, other);
+        if self.current.is_none() {
+            self.index += added;
+        }
+    }
+}
+
+// The split off nodes are freed through a clone of the allocator, which the
+// `Allocator` contract requires to stand for the same allocator
+impl<'a, T, A: Allocator + Clone> CursorMut<'a, T, A> {
+    /// Splits off everything after the current element into a new list.
+    /// On the ghost the whole list is split off.
+    pub fn split_after(&mut self) -> DoublyLinkedList<T, A> {
+        let mut split = DoublyLinkedList::new_in(self.list.alloc.clone());
+
+        match self.current {
+            Some(mut node) => unsafe {
+                if let Some(mut next) = node.as_mut().next.take() {
+                    next.as_mut().prev = None;
+                    split.head = Some(next);
+                    split.tail = self.list.tail.replace(node);
+                    split.len = self.list.len - self.index - 1;
+                    self.list.len = self.index + 1;
+                }
+            },
+            None => {
+                std::mem::swap(self.list, &mut split);
+                self.index = 0;
+            }
+        }
+
+        split
+    }
+
+    /// Splits off everything before the current element into a new list.
+    /// On the ghost the whole list is split off.
+    pub fn split_before(&mut self) -> DoublyLinkedList<T, A> {
+        let mut split = DoublyLinkedList::new_in(self.list.alloc.clone());
+
+        match self.current {
+            Some(mut node) => unsafe {
+                if let Some(mut prev) = node.as_mut().prev.take() {
+                    prev.as_mut().next = None;
+                    split.head = self.list.head.replace(node);
+                    split.tail = Some(prev);
+                    split.len = self.index;
+                    self.list.len -= self.index;
+                }
+            },
+            None => std::mem::swap(self.list, &mut split),
+        }
+
+        self.index = 0;
+        split
+    }
+}
+
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.head = node.next;
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.tail = node.prev;
+            self.len -= 1;
+            &node.value
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.head = node.next;
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.tail = node.prev;
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T, A: Allocator> Drop for DoublyLinkedList<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// The list owns its nodes exclusively, just like a chain of boxes would
+unsafe impl<T: Send, A: Allocator + Send> Send for DoublyLinkedList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for DoublyLinkedList<T, A> {}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_slice(values: &[i32]) -> DoublyLinkedList<i32> {
+        let mut list = DoublyLinkedList::new();
+        for &value in values {
+            list.push_back(value);
+        }
+        list
+    }
+
+    fn to_vec(list: &DoublyLinkedList<i32>) -> Vec<i32> {
+        let forward: Vec<i32> = list.iter().copied().collect();
+        let mut backward: Vec<i32> = list.iter().rev().copied().collect();
+        backward.reverse();
+
+        // Walking the prev links must agree with walking the next links
+        assert_eq!(forward, backward);
+        assert_eq!(forward.len(), list.len());
+        forward
+    }
+
+    #[test]
+    fn push_and_pop_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+
+        assert_eq!(to_vec(&list), vec![1, 2, 3]);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn front_and_back_mut() {
+        let mut list = from_slice(&[1, 2, 3]);
+        *list.front_mut().unwrap() = 10;
+        *list.back_mut().unwrap() = 30;
+
+        assert_eq!(to_vec(&list), vec![10, 2, 30]);
+    }
+
+    #[test]
+    fn iter_from_both_ends() {
+        let list = from_slice(&[1, 2, 3, 4]);
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = from_slice(&[1, 2, 3]);
+        for value in list.iter_mut().rev() {
+            *value *= 10;
+        }
+
+        assert_eq!(to_vec(&list), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn cursor_moves_through_the_ghost() {
+        let list = from_slice(&[1, 2, 3]);
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.peek_prev(), None);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+        assert_eq!(cursor.peek_prev(), Some(&3));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        let back = list.cursor_back();
+        assert_eq!(back.current(), Some(&3));
+        assert_eq!(back.peek_prev(), Some(&2));
+    }
+
+    #[test]
+    fn cursor_insert() {
+        let mut list = from_slice(&[2, 4]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_before(1);
+        assert_eq!(cursor.index(), Some(1));
+        cursor.insert_after(3);
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.insert_before(5);
+        cursor.insert_after(0);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 0));
+
+        assert_eq!(to_vec(&list), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn cursor_remove_current() {
+        let mut list = from_slice(&[1, 2, 3, 4]);
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(to_vec(&list), vec![2]);
+        assert_eq!(list.back(), Some(&2));
+    }
+
+    #[test]
+    fn cursor_remove_everything() {
+        let mut list = from_slice(&[1, 2, 3]);
+        let mut cursor = list.cursor_back_mut();
+        while cursor.remove_current().is_some() {
+            cursor.move_prev();
+        }
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn cursor_split() {
+        let mut list = from_slice(&[1, 2, 3, 4, 5]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+
+        let after = cursor.split_after();
+        assert_eq!(cursor.index(), Some(2));
+        let before = cursor.split_before();
+        assert_eq!(cursor.index(), Some(0));
+
+        assert_eq!(to_vec(&before), vec![1, 2]);
+        assert_eq!(to_vec(&list), vec![3]);
+        assert_eq!(to_vec(&after), vec![4, 5]);
+    }
+
+    #[test]
+    fn cursor_split_at_the_ends() {
+        let mut list = from_slice(&[1, 2]);
+        let mut cursor = list.cursor_back_mut();
+        assert!(cursor.split_after().is_empty());
+
+        cursor.move_next();
+        let everything = cursor.split_before();
+        assert_eq!(to_vec(&everything), vec![1, 2]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn cursor_splice() {
+        let mut list = from_slice(&[1, 5]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.splice_after(from_slice(&[2, 3]));
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 5));
+        cursor.splice_before(from_slice(&[4]));
+        assert_eq!(cursor.index(), Some(4));
+
+        cursor.move_next();
+        cursor.splice_before(from_slice(&[6, 7]));
+        cursor.splice_after(from_slice(&[-1, 0]));
+        cursor.splice_after(DoublyLinkedList::new());
+
+        assert_eq!(to_vec(&list), vec![-1, 0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn cursor_splice_from_other_arena() {
+        use crate::allocator::BumpAllocator;
+
+        let arena_list = |values: &[i32]| {
+            let mut list = DoublyLinkedList::new_in(BumpAllocator::new(4096));
+            values.iter().for_each(|&value| list.push_back(value));
+            list
+        };
+
+        // Each spliced list frees its own arena as it is consumed, so its
+        // nodes must not end up in `list`
+        let mut list = arena_list(&[1, 4]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.splice_after(arena_list(&[2, 3]));
+        assert_eq!(cursor.index(), Some(0));
+        cursor.splice_before(arena_list(&[-1, 0]));
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.splice_before(arena_list(&[5, 6]));
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![-1, 0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3, 2, 1, 0, -1]);
+        assert_eq!(list.len(), 8);
+    }
+
+    #[test]
+    fn counting_allocator() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = DoublyLinkedList::new_in(&counter);
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.remove_current();
+        let tail = cursor.split_after();
+        assert_eq!(counter.live_allocations(), 4);
+
+        drop(tail);
+        assert_eq!(counter.live_allocations(), 2);
+        drop(list);
+        assert_eq!(counter.live_allocations(), 0);
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// A position past the end of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    pub index: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index ({}) is out of bounds len ({})", self.index, self.len)
+    }
+}
+
+impl std::error::Error for IndexOutOfBounds {}
+
+/// A failed insertion, handing back the value that couldn't be inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsertError<T> {
+    pub error: IndexOutOfBounds,
+    pub value: T,
+}
+
+impl<T> InsertError<T> {
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "insertion {}", self.error)
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for InsertError<T> {}
@@ -0,0 +1,388 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
+
+use super::hash_table_linear_probing::{self, DefaultBuildHasher, HashTable};
+
+/// A set of keys, stored as a `HashTable` whose values are all `()`.
+pub struct HashSet<K, S = DefaultBuildHasher> {
+    table: HashTable<K, (), S>,
+}
+
+impl<K> HashSet<K, DefaultBuildHasher> {
+    pub fn new() -> Self {
+        Self { table: HashTable::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            table: HashTable::with_capacity(capacity),
+        }
+    }
+}
+
+impl<K, S> HashSet<K, S> {
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            table: HashTable::with_hasher(hash_builder),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter {
+            keys: self.table.keys(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.table.clear();
+    }
+}
+
+impl<K: Hash + Eq, S: BuildHasher> HashSet<K, S> {
+    /// Returns whether the key was newly added.
+    pub fn insert(&mut self, key: K) -> bool {
+        self.table.insert(key, ()).is_none()
+    }
+
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.exists(key)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.get_key_value(key).map(|(key, _)| key)
+    }
+
+    /// Returns whether the key was present.
+    pub fn remove<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.remove(key).is_some()
+    }
+
+    pub fn take<Q>(&mut self, key: &Q) -> Option<K>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.remove_entry(key).map(|(key, _)| key)
+    }
+
+    pub fn retain<F: FnMut(&K) -> bool>(&mut self, mut f: F) {
+        self.table.retain(|key, _| f(key));
+    }
+
+    pub fn union<'a>(&'a self, other: &'a HashSet<K, S>) -> Union<'a, K, S> {
+        Union {
+            iter: self.iter(),
+            rest: other.difference(self),
+        }
+    }
+
+    pub fn intersection<'a>(&'a self, other: &'a HashSet<K, S>) -> Intersection<'a, K, S> {
+        // Walking the smaller set means fewer lookups in the other one
+        let (smaller, larger) = if self.len() <= other.len() { (self, other) } else { (other, self) };
+
+        Intersection {
+            iter: smaller.iter(),
+            other: larger,
+        }
+    }
+
+    pub fn difference<'a>(&'a self, other: &'a HashSet<K, S>) -> Difference<'a, K, S> {
+        Difference {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    pub fn symmetric_difference<'a>(&'a self, other: &'a HashSet<K, S>) -> SymmetricDifference<'a, K, S> {
+        SymmetricDifference {
+            first: self.difference(other),
+            second: other.difference(self),
+        }
+    }
+
+    pub fn is_disjoint(&self, other: &HashSet<K, S>) -> bool {
+        self.intersection(other).next().is_none()
+    }
+
+    pub fn is_subset(&self, other: &HashSet<K, S>) -> bool {
+        self.len() <= other.len() && self.iter().all(|key| other.contains(key))
+    }
+
+    pub fn is_superset(&self, other: &HashSet<K, S>) -> bool {
+        other.is_subset(self)
+    }
+}
+
+pub struct Iter<'a, K> {
+    keys: hash_table_linear_probing::Keys<'a, K, ()>,
+}
+
+impl<'a, K> Iterator for Iter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.keys.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<K> ExactSizeIterator for Iter<'_, K> {}
+
+pub struct IntoIter<K> {
+    inner: hash_table_linear_probing::IntoIter<K, ()>,
+}
+
+impl<K> Iterator for IntoIter<K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K> ExactSizeIterator for IntoIter<K> {}
+
+/// Keys of the first set, then the keys of the second set missing from it.
+pub struct Union<'a, K, S> {
+    iter: Iter<'a, K>,
+    rest: Difference<'a, K, S>,
+}
+
+impl<'a, K: Hash + Eq, S: BuildHasher> Iterator for Union<'a, K, S> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().or_else(|| self.rest.next())
+    }
+}
+
+pub struct Intersection<'a, K, S> {
+    iter: Iter<'a, K>,
+    other: &'a HashSet<K, S>,
+}
+
+impl<'a, K: Hash + Eq, S: BuildHasher> Iterator for Intersection<'a, K, S> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        let other = self.other;
+        self.iter.by_ref().find(|key| other.contains(*key))
+    }
+}
+
+pub struct Difference<'a, K, S> {
+    iter: Iter<'a, K>,
+    other: &'a HashSet<K, S>,
+}
+
+impl<'a, K: Hash + Eq, S: BuildHasher> Iterator for Difference<'a, K, S> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        let other = self.other;
+        self.iter.by_ref().find(|key| !other.contains(*key))
+    }
+}
+
+pub struct SymmetricDifference<'a, K, S> {
+    first: Difference<'a, K, S>,
+    second: Difference<'a, K, S>,
+}
+
+impl<'a, K: Hash + Eq, S: BuildHasher> Iterator for SymmetricDifference<'a, K, S> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.first.next().or_else(|| self.second.next())
+    }
+}
+
+impl<K, S> IntoIterator for HashSet<K, S> {
+    type Item = K;
+    type IntoIter = IntoIter<K>;
+
+    fn into_iter(self) -> IntoIter<K> {
+        IntoIter {
+            inner: self.table.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, S> IntoIterator for &'a HashSet<K, S> {
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K>;
+
+    fn into_iter(self) -> Iter<'a, K> {
+        self.iter()
+    }
+}
+
+impl<K: Hash + Eq, S: BuildHasher> Extend<K> for HashSet<K, S> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        self.table.extend(iter.into_iter().map(|key| (key, ())));
+    }
+}
+
+impl<K: Hash + Eq, S: BuildHasher + Default> FromIterator<K> for HashSet<K, S> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = HashSet::with_hasher(S::default());
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: Hash + Eq, S: BuildHasher> PartialEq for HashSet<K, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.table == other.table
+    }
+}
+
+impl<K: Hash + Eq, S: BuildHasher> Eq for HashSet<K, S> {}
+
+impl<K: fmt::Debug, S> fmt::Debug for HashSet<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K> Default for HashSet<K, DefaultBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<'a, I: Iterator<Item = &'a i32>>(iter: I) -> Vec<i32> {
+        let mut items: Vec<i32> = iter.copied().collect();
+        items.sort();
+        items
+    }
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = HashSet::new();
+
+        assert!(set.insert("a".to_string()));
+        assert!(set.insert("b".to_string()));
+        assert!(!set.insert("a".to_string()));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.contains("a"));
+        assert!(!set.contains("c"));
+        assert_eq!(set.get("b"), Some(&"b".to_string()));
+
+        assert!(set.remove("a"));
+        assert!(!set.remove("a"));
+        assert_eq!(set.take("b"), Some("b".to_string()));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn union() {
+        let a: HashSet<i32> = (0..5).collect();
+        let b: HashSet<i32> = (3..8).collect();
+
+        assert_eq!(sorted(a.union(&b)), (0..8).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn intersection() {
+        let a: HashSet<i32> = (0..5).collect();
+        let b: HashSet<i32> = (3..8).collect();
+
+        assert_eq!(sorted(a.intersection(&b)), vec![3, 4]);
+        assert_eq!(sorted(b.intersection(&a)), vec![3, 4]);
+    }
+
+    #[test]
+    fn difference() {
+        let a: HashSet<i32> = (0..5).collect();
+        let b: HashSet<i32> = (3..8).collect();
+
+        assert_eq!(sorted(a.difference(&b)), vec![0, 1, 2]);
+        assert_eq!(sorted(b.difference(&a)), vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn symmetric_difference() {
+        let a: HashSet<i32> = (0..5).collect();
+        let b: HashSet<i32> = (3..8).collect();
+
+        assert_eq!(sorted(a.symmetric_difference(&b)), vec![0, 1, 2, 5, 6, 7]);
+    }
+
+    #[test]
+    fn set_operations_are_lazy() {
+        let a: HashSet<i32> = (0..1000).collect();
+        let b: HashSet<i32> = (500..1500).collect();
+
+        assert_eq!(a.intersection(&b).take(3).count(), 3);
+        assert_eq!(a.union(&b).count(), 1500);
+    }
+
+    #[test]
+    fn subset_superset_disjoint() {
+        let a: HashSet<i32> = (0..3).collect();
+        let b: HashSet<i32> = (0..5).collect();
+        let c: HashSet<i32> = (10..12).collect();
+
+        assert!(a.is_subset(&b));
+        assert!(!b.is_subset(&a));
+        assert!(b.is_superset(&a));
+        assert!(a.is_disjoint(&c));
+        assert!(!a.is_disjoint(&b));
+        assert!(HashSet::<i32>::new().is_subset(&a));
+    }
+
+    #[test]
+    fn retain_and_into_iter() {
+        let mut set: HashSet<i32> = (0..10).collect();
+        set.retain(|key| key % 2 == 0);
+
+        let mut keys: Vec<i32> = set.into_iter().collect();
+        keys.sort();
+        assert_eq!(keys, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn eq_and_debug() {
+        let a: HashSet<i32> = (0..3).collect();
+        let b: HashSet<i32> = (0..3).rev().collect();
+        assert_eq!(a, b);
+
+        let single: HashSet<i32> = std::iter::once(1).collect();
+        assert_eq!(format!("{:?}", single), "{1}");
+    }
+}
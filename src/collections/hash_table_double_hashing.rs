@@ -0,0 +1,42 @@
+use super::hash_table_linear_probing::DefaultBuildHasher;
+use super::hash_table_open_addressing::{OpenAddressingTable, ProbeStep};
+
+/// Steps a fixed distance taken from a second hash of the key.
+pub struct DoubleHashing;
+
+// The low bits of the hash pick the home slot and the high bits the step. An
+// odd step is coprime with a power of two capacity, so the sequence visits
+// every slot before repeating.
+impl ProbeStep for DoubleHashing {
+    fn step(hash: u64, _probe: usize, mask: usize) -> usize {
+        ((hash >> 32) as usize | 1) & mask
+    }
+}
+
+/// Open addressing table where the probe step comes from a second hash of the
+/// key. Keys sharing a home slot still follow different sequences, which
+/// avoids both the primary clustering of linear probing and the secondary
+/// clustering of quadratic probing.
+pub type HashTable<K, V, S = DefaultBuildHasher> = OpenAddressingTable<K, V, S, DoubleHashing>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::Djb2BuildHasher;
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map_basics(HashTable::new);
+        crate::collections::map::tests::check_map(HashTable::with_hasher(Djb2BuildHasher::default()));
+    }
+
+    #[test]
+    fn step_is_odd() {
+        let mask = 7;
+        for hash in (0..100u64).map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15)) {
+            let step = DoubleHashing::step(hash, 1, mask);
+            assert_eq!(step % 2, 1);
+            assert!(step <= mask);
+        }
+    }
+}
@@ -0,0 +1,582 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use super::hash_table_open_addressing::{self, OpenAddressingTable, ProbeStep};
+
+pub use super::hash_table_open_addressing::{Drain, IntoIter, Iter, IterMut, Keys, Values, ValuesMut};
+
+pub type DefaultBuildHasher = RandomState;
+
+// approach to the djb2 hash function, kept around as a cheap deterministic hasher
+pub struct Djb2Hasher {
+    hash: u64,
+}
+
+impl Default for Djb2Hasher {
+    fn default() -> Self {
+        Self { hash: 5381 }
+    }
+}
+
+impl Hasher for Djb2Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.hash = ((self.hash << 5).wrapping_add(self.hash)).wrapping_add(b as u64);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+pub type Djb2BuildHasher = BuildHasherDefault<Djb2Hasher>;
+
+#[derive(Clone)]
+pub struct KeyValue<K, V> {
+    pub key: K,
+    pub value: V,
+}
+
+/// Steps to the next slot every time.
+pub struct Linear;
+
+impl ProbeStep for Linear {
+    fn step(_hash: u64, _probe: usize, _mask: usize) -> usize {
+        1
+    }
+}
+
+/// Open addressing table that walks to the next slot on a collision. Runs of
+/// neighbouring keys cluster together, but the walk stays within a few cache
+/// lines.
+pub type HashTable<K, V, S = DefaultBuildHasher> = OpenAddressingTable<K, V, S, Linear>;
+
+pub type Entry<'a, K, V, S = DefaultBuildHasher> = hash_table_open_addressing::Entry<'a, K, V, S, Linear>;
+pub type OccupiedEntry<'a, K, V, S = DefaultBuildHasher> = hash_table_open_addressing::OccupiedEntry<'a, K, V, S, Linear>;
+pub type VacantEntry<'a, K, V, S = DefaultBuildHasher> = hash_table_open_addressing::VacantEntry<'a, K, V, S, Linear>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Helper function to populate a hash table
+    fn populate_hash_table(hash_table: &mut HashTable<String, i32>, from: i32, to: i32) {
+        for i in from..to {
+            let key = format!("key{}", i);
+            hash_table.add(key.clone(), i);
+        }
+    }
+
+    #[test]
+    fn new() {
+        let hash_table: HashTable<String, i32> = HashTable::new();
+        assert_eq!(hash_table.capacity(), 0);
+        assert_eq!(hash_table.len(), 0);
+        assert!(hash_table.is_empty());
+        assert_eq!(hash_table.get("key0"), None);
+    }
+
+    #[test]
+    fn add() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        assert_eq!(hash_table.len(), 3);
+        assert_eq!(hash_table.capacity(), 8);
+    }
+
+    #[test]
+    fn add_existing_key() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+        hash_table.add("key1".to_string(), 10);
+
+        assert_eq!(hash_table.len(), 3);
+        assert_eq!(hash_table.get("key1"), Some(&10));
+    }
+
+    #[test]
+    fn exists() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        for i in 0..3 {
+            let key = format!("key{}", i);
+            assert!(hash_table.exists(&key));
+        }
+
+        assert!(!hash_table.exists("key10"));
+    }
+
+    #[test]
+    fn get() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        for i in 0..3 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.get(&key), Some(&i));
+        }
+
+        assert_eq!(hash_table.get("key10"), None);
+    }
+
+    #[test]
+    fn remove() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        for i in 0..3 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.remove(&key), Some(i));
+        }
+
+        assert_eq!(hash_table.remove("key10"), None);
+    }
+
+    #[test]
+    fn remove_and_add() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        for i in 0..3 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.remove(&key), Some(i));
+        }
+
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        for i in 0..3 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.get(&key), Some(&i));
+        }
+    }
+
+    #[test]
+    fn non_copy_values() {
+        let mut hash_table: HashTable<String, Vec<u8>> = HashTable::new();
+        hash_table.add("bytes".to_string(), vec![1, 2, 3]);
+        hash_table.add("more".to_string(), vec![4]);
+
+        assert_eq!(hash_table.get("bytes"), Some(&vec![1, 2, 3]));
+        assert_eq!(hash_table.remove("more"), Some(vec![4]));
+        assert_eq!(hash_table.get("more"), None);
+    }
+
+    #[test]
+    fn struct_keys() {
+        #[derive(Hash, PartialEq, Eq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let mut hash_table = HashTable::new();
+        hash_table.add(Point { x: 1, y: 2 }, "a");
+        hash_table.add(Point { x: 2, y: 1 }, "b");
+
+        assert_eq!(hash_table.get(&Point { x: 1, y: 2 }), Some(&"a"));
+        assert_eq!(hash_table.get(&Point { x: 2, y: 1 }), Some(&"b"));
+        assert!(!hash_table.exists(&Point { x: 0, y: 0 }));
+    }
+
+    #[test]
+    fn custom_hasher() {
+        let mut hash_table: HashTable<u64, u64, Djb2BuildHasher> = HashTable::with_hasher(Djb2BuildHasher::default());
+        for i in 0..10 {
+            hash_table.add(i, i * i);
+        }
+
+        for i in 0..10 {
+            assert_eq!(hash_table.get(&i), Some(&(i * i)));
+        }
+    }
+
+    #[test]
+    fn djb2_hasher() {
+        let mut hasher = Djb2Hasher::default();
+        hasher.write(b"ab");
+
+        // ((5381 * 33) + 'a') * 33 + 'b'
+        assert_eq!(hasher.finish(), 5863208);
+    }
+
+    #[test]
+    fn grow_keeps_every_key() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 100_000);
+
+        assert_eq!(hash_table.len(), 100_000);
+        assert!(hash_table.capacity().is_power_of_two());
+        assert!(hash_table.len() as f64 <= hash_table.capacity() as f64 * hash_table.max_load_factor());
+
+        for i in 0..100_000 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.get(&key), Some(&i));
+        }
+    }
+
+    #[test]
+    fn shrink_after_mass_delete() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 10_000);
+        let grown = hash_table.capacity();
+
+        for i in 0..9_990 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.remove(&key), Some(i));
+        }
+
+        assert!(hash_table.capacity() < grown / 100);
+        assert_eq!(hash_table.len(), 10);
+        for i in 9_990..10_000 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.get(&key), Some(&i));
+        }
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut hash_table: HashTable<String, i32> = HashTable::with_capacity(1000);
+        let capacity = hash_table.capacity();
+        assert!(capacity.is_power_of_two());
+
+        populate_hash_table(&mut hash_table, 0, 1000);
+        assert_eq!(hash_table.capacity(), capacity);
+    }
+
+    #[test]
+    fn reserve() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 10);
+
+        hash_table.reserve(1000);
+        let capacity = hash_table.capacity();
+        populate_hash_table(&mut hash_table, 10, 1010);
+
+        assert_eq!(hash_table.capacity(), capacity);
+        assert_eq!(hash_table.get("key1009"), Some(&1009));
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut hash_table: HashTable<String, i32> = HashTable::with_capacity(1000);
+        populate_hash_table(&mut hash_table, 0, 10);
+
+        hash_table.shrink_to_fit();
+        assert_eq!(hash_table.capacity(), 16);
+        assert_eq!(hash_table.get("key9"), Some(&9));
+    }
+
+    #[test]
+    fn max_load_factor() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 100);
+        let capacity = hash_table.capacity();
+
+        hash_table.set_max_load_factor(0.25);
+        assert!(hash_table.capacity() > capacity);
+        assert!(hash_table.len() as f64 <= hash_table.capacity() as f64 * 0.25);
+
+        for i in 0..100 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.get(&key), Some(&i));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_max_load_factor() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        hash_table.set_max_load_factor(1.0);
+    }
+
+    // Sends every key to the same slot, so they all share one probe chain
+    #[derive(Default)]
+    struct CollidingHasher;
+
+    impl Hasher for CollidingHasher {
+        fn write(&mut self, _bytes: &[u8]) {}
+
+        fn finish(&self) -> u64 {
+            0
+        }
+    }
+
+    fn colliding_hash_table() -> HashTable<i32, i32, BuildHasherDefault<CollidingHasher>> {
+        let mut hash_table = HashTable::with_hasher(BuildHasherDefault::default());
+        for i in 0..5 {
+            hash_table.add(i, i);
+        }
+
+        hash_table
+    }
+
+    #[test]
+    fn lookups_probe_past_tombstones() {
+        let mut hash_table = colliding_hash_table();
+        assert_eq!(hash_table.remove(&0), Some(0));
+        assert_eq!(hash_table.remove(&2), Some(2));
+
+        for i in [1, 3, 4] {
+            assert!(hash_table.exists(&i));
+            assert_eq!(hash_table.get(&i), Some(&i));
+        }
+        assert_eq!(hash_table.remove(&4), Some(4));
+        assert!(!hash_table.exists(&0));
+    }
+
+    #[test]
+    fn add_does_not_duplicate_behind_tombstone() {
+        let mut hash_table = colliding_hash_table();
+        hash_table.remove(&0);
+
+        // The tombstone left by key 0 comes before key 3 in the chain
+        hash_table.add(3, 30);
+
+        assert_eq!(hash_table.len(), 4);
+        assert_eq!(hash_table.get(&3), Some(&30));
+        assert_eq!(hash_table.remove(&3), Some(30));
+        assert!(!hash_table.exists(&3));
+    }
+
+    #[test]
+    fn differential_against_std_hash_map() {
+        use std::collections::HashMap;
+
+        // xorshift64, so the test is reproducible without extra dependencies
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut hash_table: HashTable<u64, u64> = HashTable::new();
+        let mut expected: HashMap<u64, u64> = HashMap::new();
+
+        for step in 0..200_000 {
+            let key = next() % 512;
+            match next() % 4 {
+                0 | 1 => {
+                    let value = next();
+                    hash_table.add(key, value);
+                    expected.insert(key, value);
+                }
+                2 => assert_eq!(hash_table.remove(&key), expected.remove(&key), "step {}", step),
+                _ => {
+                    assert_eq!(hash_table.get(&key), expected.get(&key), "step {}", step);
+                    assert_eq!(hash_table.exists(&key), expected.contains_key(&key), "step {}", step);
+                }
+            }
+
+            assert_eq!(hash_table.len(), expected.len(), "step {}", step);
+        }
+
+        for (key, value) in &expected {
+            assert_eq!(hash_table.get(key), Some(value));
+        }
+    }
+
+    #[test]
+    fn insert_returns_previous_value() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+
+        assert_eq!(hash_table.insert("a".to_string(), 1), None);
+        assert_eq!(hash_table.insert("a".to_string(), 2), Some(1));
+        assert_eq!(hash_table.get("a"), Some(&2));
+        assert_eq!(hash_table.len(), 1);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut hash_table: HashTable<String, Vec<i32>> = HashTable::new();
+        hash_table.insert("a".to_string(), vec![1]);
+
+        hash_table.get_mut("a").unwrap().push(2);
+        assert_eq!(hash_table.get("a"), Some(&vec![1, 2]));
+        assert_eq!(hash_table.get_mut("b"), None);
+    }
+
+    #[test]
+    fn get_key_value() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        assert_eq!(hash_table.get_key_value("key1"), Some((&"key1".to_string(), &1)));
+        assert_eq!(hash_table.get_key_value("key3"), None);
+    }
+
+    #[test]
+    fn entry_counts_words() {
+        let mut hash_table: HashTable<&str, usize> = HashTable::new();
+        for word in "a b a c b a".split(' ') {
+            *hash_table.entry(word).or_insert(0) += 1;
+        }
+
+        assert_eq!(hash_table.get("a"), Some(&3));
+        assert_eq!(hash_table.get("b"), Some(&2));
+        assert_eq!(hash_table.get("c"), Some(&1));
+    }
+
+    #[test]
+    fn entry_groups_values() {
+        let mut hash_table: HashTable<bool, Vec<i32>> = HashTable::new();
+        for i in 0..6 {
+            hash_table.entry(i % 2 == 0).or_default().push(i);
+        }
+
+        assert_eq!(hash_table.get(&true), Some(&vec![0, 2, 4]));
+        assert_eq!(hash_table.get(&false), Some(&vec![1, 3, 5]));
+    }
+
+    #[test]
+    fn entry_and_modify() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+
+        hash_table.entry("a".to_string()).and_modify(|v| *v += 1).or_insert_with(|| 10);
+        assert_eq!(hash_table.get("a"), Some(&10));
+
+        hash_table.entry("a".to_string()).and_modify(|v| *v += 1).or_insert_with(|| 10);
+        assert_eq!(hash_table.get("a"), Some(&11));
+
+        let value = hash_table.entry("bb".to_string()).or_insert_with_key(|key| key.len() as i32);
+        assert_eq!(*value, 2);
+    }
+
+    #[test]
+    fn entry_variants() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        hash_table.insert("a".to_string(), 1);
+
+        match hash_table.entry("a".to_string()) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), "a");
+                assert_eq!(entry.insert(5), 1);
+                assert_eq!(entry.remove_entry(), ("a".to_string(), 5));
+            }
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+
+        match hash_table.entry("a".to_string()) {
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), "a");
+                *entry.insert(7) += 1;
+            }
+        }
+
+        assert_eq!(hash_table.get("a"), Some(&8));
+        assert_eq!(hash_table.len(), 1);
+        assert_eq!(hash_table.remove_entry("a"), Some(("a".to_string(), 8)));
+        assert!(hash_table.is_empty());
+    }
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map(HashTable::new());
+    }
+
+    #[test]
+    fn iter_skips_empty_and_deleted_slots() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 10);
+        hash_table.remove("key3");
+        hash_table.remove("key7");
+
+        let iter = hash_table.iter();
+        assert_eq!(iter.len(), 8);
+
+        let mut values: Vec<i32> = iter.map(|(_, value)| *value).collect();
+        values.sort();
+        assert_eq!(values, vec![0, 1, 2, 4, 5, 6, 8, 9]);
+    }
+
+    #[test]
+    fn iter_mut_and_values_mut() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 5);
+
+        for (_, value) in hash_table.iter_mut() {
+            *value *= 10;
+        }
+        for value in hash_table.values_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(hash_table.get("key3"), Some(&31));
+    }
+
+    #[test]
+    fn keys_and_values() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 3);
+
+        let mut keys: Vec<&String> = hash_table.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["key0", "key1", "key2"]);
+
+        assert_eq!(hash_table.values().sum::<i32>(), 3);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut hash_table: HashTable<String, Vec<u8>> = HashTable::new();
+        hash_table.insert("a".to_string(), vec![1]);
+        hash_table.insert("b".to_string(), vec![2]);
+
+        let mut entries: Vec<(String, Vec<u8>)> = hash_table.into_iter().collect();
+        entries.sort();
+        assert_eq!(entries, vec![("a".to_string(), vec![1]), ("b".to_string(), vec![2])]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 100);
+
+        hash_table.retain(|_, value| *value % 10 == 0);
+
+        assert_eq!(hash_table.len(), 10);
+        for i in 0..100 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.exists(&key), i % 10 == 0);
+        }
+    }
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut hash_table: HashTable<i32, i32> = (0..5).map(|i| (i, i * i)).collect();
+        hash_table.extend(vec![(5, 25), (0, -1)]);
+
+        assert_eq!(hash_table.len(), 6);
+        assert_eq!(hash_table.get(&0), Some(&-1));
+        assert_eq!(hash_table.get(&5), Some(&25));
+    }
+
+    #[test]
+    fn partial_eq() {
+        let a: HashTable<i32, i32> = (0..100).map(|i| (i, i)).collect();
+        let mut b: HashTable<i32, i32> = HashTable::with_capacity(1000);
+        b.extend((0..100).rev().map(|i| (i, i)));
+
+        assert_eq!(a, b);
+
+        b.insert(0, 1);
+        assert_ne!(a, b);
+
+        b.remove(&0);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let mut hash_table: HashTable<&str, i32> = HashTable::new();
+        assert_eq!(format!("{:?}", hash_table), "{}");
+
+        hash_table.insert("a", 1);
+        assert_eq!(format!("{:?}", hash_table), "{\"a\": 1}");
+    }
+}
@@ -0,0 +1,908 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+
+use super::hash_table_linear_probing::{DefaultBuildHasher, KeyValue};
+use super::map::Map;
+
+/// How an open addressing table walks away from a key's home slot.
+pub trait ProbeStep {
+    /// Distance from the `probe`th slot visited, counting the home slot as 1,
+    /// to the next one, for a key with `hash` in a table of `mask + 1` slots.
+    /// The steps must visit every slot of a power of two table before
+    /// repeating one.
+    fn step(hash: u64, probe: usize, mask: usize) -> usize;
+}
+
+// A deleted slot is kept as a tombstone so probe sequences running through it
+// still reach the entries placed after it
+#[derive(Clone)]
+enum Slot<K, V> {
+    Empty,
+    Deleted,
+    Occupied(KeyValue<K, V>),
+}
+
+enum Probe {
+    Found(usize),
+    Vacant(usize),
+}
+
+/// Open addressing table with tombstone deletion, shared by the collision
+/// strategies that only differ in the probe step `P`.
+pub struct OpenAddressingTable<K, V, S, P> {
+    data: Vec<Slot<K, V>>,
+    len: usize,
+    deleted: usize,
+    max_load_factor: f64,
+    hash_builder: S,
+    _step: PhantomData<P>,
+}
+
+impl<K, V, P> OpenAddressingTable<K, V, DefaultBuildHasher, P> {
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, DefaultBuildHasher::default())
+    }
+}
+
+impl<K, V, S, P> OpenAddressingTable<K, V, S, P> {
+    const MIN_CAPACITY: usize = 8;
+    const DEFAULT_MAX_LOAD_FACTOR: f64 = 0.75;
+
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            data: Vec::new(),
+            len: 0,
+            deleted: 0,
+            max_load_factor: Self::DEFAULT_MAX_LOAD_FACTOR,
+            hash_builder,
+            _step: PhantomData,
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut hash_table = Self::with_hasher(hash_builder);
+        if capacity > 0 {
+            hash_table.data = Self::empty_slots(hash_table.slots_for(capacity));
+        }
+
+        hash_table
+    }
+
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of slots in the table. Only `max_load_factor` of them are
+    /// filled before the table grows.
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn max_load_factor(&self) -> f64 {
+        self.max_load_factor
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.data.iter(),
+            len: self.len,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            slots: self.data.iter_mut(),
+            len: self.len,
+        }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { inner: self.iter_mut() }
+    }
+
+    /// Removes every entry, handing them out as it goes. The slots are kept,
+    /// so the table can be refilled without growing again.
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        let len = self.len;
+        self.len = 0;
+        self.deleted = 0;
+
+        Drain {
+            slots: self.data.iter_mut(),
+            len,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.drain();
+    }
+
+    fn empty_slots(count: usize) -> Vec<Slot<K, V>> {
+        std::iter::repeat_with(|| Slot::Empty).take(count).collect()
+    }
+
+    // Smallest power of two number of slots that holds `entries` without
+    // going over the max load factor
+    fn slots_for(&self, entries: usize) -> usize {
+        let slots = (entries as f64 / self.max_load_factor).ceil() as usize;
+        slots.max(Self::MIN_CAPACITY).checked_next_power_of_two().expect("capacity overflow")
+    }
+
+    // Entries plus tombstones the current slots can take before growing
+    fn threshold(&self) -> usize {
+        (self.data.len() as f64 * self.max_load_factor) as usize
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, P: ProbeStep> OpenAddressingTable<K, V, S, P> {
+    pub fn set_max_load_factor(&mut self, max_load_factor: f64) {
+        if !(max_load_factor > 0.0 && max_load_factor < 1.0) {
+            panic!("Max load factor ({}) must be between 0 and 1", max_load_factor);
+        }
+
+        self.max_load_factor = max_load_factor;
+        if self.len + self.deleted > self.threshold() {
+            self.resize(self.slots_for(self.len));
+        }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required + self.deleted > self.threshold() {
+            self.resize(self.slots_for(required));
+        }
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        if self.len == 0 {
+            self.data = Vec::new();
+            self.deleted = 0;
+            return;
+        }
+
+        let slots = self.slots_for(self.len);
+        if slots < self.data.len() {
+            self.resize(slots);
+        }
+    }
+
+    // Rebuilds the table with `capacity` slots, rehashing every entry and
+    // dropping the tombstones on the way
+    fn resize(&mut self, capacity: usize) {
+        let old_data = std::mem::replace(&mut self.data, Self::empty_slots(capacity));
+        self.deleted = 0;
+
+        for slot in old_data {
+            if let Slot::Occupied(key_value) = slot {
+                // The capacity is always a power of two, so masking replaces the modulo
+                let hash = self.hash_builder.hash_one(&key_value.key);
+                let mask = self.data.len() - 1;
+                let mut index = hash as usize & mask;
+                let mut probes = 1;
+                while !matches!(self.data[index], Slot::Empty) {
+                    index = (index + P::step(hash, probes, mask)) & mask;
+                    probes += 1;
+                }
+
+                self.data[index] = Slot::Occupied(key_value);
+            }
+        }
+    }
+
+    // Walks the probe sequence of `key` once, returning where it ends and how
+    // many slots it inspected. Tombstones never end the walk, only an empty
+    // slot does, so entries placed after a removed one are still found. If
+    // the key is missing, the first reusable slot is returned.
+    fn probe<Q>(&self, key: &Q) -> (Probe, usize)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash_builder.hash_one(key);
+        let mask = self.data.len() - 1;
+        let mut index = hash as usize & mask;
+        let mut first_tombstone = None;
+
+        for probes in 1..=self.data.len() {
+            match &self.data[index] {
+                Slot::Empty => return (Probe::Vacant(first_tombstone.unwrap_or(index)), probes),
+                Slot::Deleted => {
+                    first_tombstone.get_or_insert(index);
+                }
+                Slot::Occupied(key_value) if key_value.key.borrow() == key => return (Probe::Found(index), probes),
+                Slot::Occupied(_) => {}
+            }
+
+            index = (index + P::step(hash, probes, mask)) & mask;
+        }
+
+        // The load factor always leaves an empty slot, but stay safe regardless
+        (Probe::Vacant(first_tombstone.unwrap_or(index)), self.data.len())
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        match self.probe(key).0 {
+            Probe::Found(index) => Some(index),
+            Probe::Vacant(_) => None,
+        }
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S, P> {
+        let mut probe = if self.data.is_empty() { None } else { Some(self.probe(&key).0) };
+
+        // Only a missing key needs room, and reserving it up front means a
+        // vacant entry can be filled without resizing. A resize moves every
+        // slot, so the key has to be probed for again.
+        if !matches!(probe, Some(Probe::Found(_))) && self.reserve_one() {
+            probe = Some(self.probe(&key).0);
+        }
+
+        match probe.expect("reserve_one allocates an empty table") {
+            Probe::Found(index) => Entry::Occupied(OccupiedEntry {
+                hash_table: self,
+                index,
+            }),
+            Probe::Vacant(index) => Entry::Vacant(VacantEntry {
+                hash_table: self,
+                key,
+                index,
+            }),
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    pub fn add(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    pub fn exists<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let key_value = self.occupied(self.find(key)?);
+        Some((&key_value.key, &key_value.value))
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(&mut self.occupied_mut(index).value)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let key_value = self.remove_at(index);
+        Some((key_value.key, key_value.value))
+    }
+
+    fn occupied(&self, index: usize) -> &KeyValue<K, V> {
+        match &self.data[index] {
+            Slot::Occupied(key_value) => key_value,
+            _ => unreachable!("probe only finds occupied slots"),
+        }
+    }
+
+    fn occupied_mut(&mut self, index: usize) -> &mut KeyValue<K, V> {
+        match &mut self.data[index] {
+            Slot::Occupied(key_value) => key_value,
+            _ => unreachable!("probe only finds occupied slots"),
+        }
+    }
+
+    fn remove_at(&mut self, index: usize) -> KeyValue<K, V> {
+        match std::mem::replace(&mut self.data[index], Slot::Deleted) {
+            Slot::Occupied(key_value) => {
+                self.len -= 1;
+                self.deleted += 1;
+                self.shrink_if_sparse();
+                key_value
+            }
+            _ => unreachable!("probe only finds occupied slots"),
+        }
+    }
+
+    // Makes room for one more entry and returns whether the slots moved.
+    // When tombstones alone push the table over its threshold, rehashing in
+    // place is enough.
+    fn reserve_one(&mut self) -> bool {
+        if self.data.is_empty() {
+            self.resize(self.slots_for(1));
+        } else if self.len + 1 > self.threshold() {
+            self.resize(self.data.len() * 2);
+        } else if self.len + self.deleted + 1 > self.threshold() {
+            self.resize(self.data.len());
+        } else {
+            return false;
+        }
+        true
+    }
+
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        for slot in self.data.iter_mut() {
+            if let Slot::Occupied(key_value) = slot {
+                if !f(&key_value.key, &mut key_value.value) {
+                    *slot = Slot::Deleted;
+                    self.len -= 1;
+                    self.deleted += 1;
+                }
+            }
+        }
+
+        if self.deleted > self.data.len() / 4 {
+            self.resize(self.data.len());
+        }
+    }
+
+    // Halves the table once it drops below a quarter of its threshold, which
+    // leaves it half full afterwards so an insert right after can't regrow it.
+    // Otherwise tombstones are purged once they fill a quarter of the slots,
+    // since every lookup has to walk past them.
+    fn shrink_if_sparse(&mut self) {
+        if self.data.len() > Self::MIN_CAPACITY && self.len < self.threshold() / 4 {
+            self.resize(self.data.len() / 2);
+        } else if self.deleted > self.data.len() / 4 {
+            self.resize(self.data.len());
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, P: ProbeStep> Map<K, V> for OpenAddressingTable<K, V, S, P> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        OpenAddressingTable::insert(self, key, value)
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        OpenAddressingTable::get(self, key)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        OpenAddressingTable::get_mut(self, key)
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        OpenAddressingTable::remove(self, key)
+    }
+
+    fn probe_length<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        match self.probe(key) {
+            (Probe::Found(_), probes) => Some(probes),
+            (Probe::Vacant(_), _) => None,
+        }
+    }
+}
+
+pub enum Entry<'a, K, V, S, P> {
+    Occupied(OccupiedEntry<'a, K, V, S, P>),
+    Vacant(VacantEntry<'a, K, V, S, P>),
+}
+
+pub struct OccupiedEntry<'a, K, V, S, P> {
+    hash_table: &'a mut OpenAddressingTable<K, V, S, P>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, S, P> {
+    hash_table: &'a mut OpenAddressingTable<K, V, S, P>,
+    key: K,
+    index: usize,
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher, P: ProbeStep> Entry<'a, K, V, S, P> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher, P: ProbeStep> OccupiedEntry<'a, K, V, S, P> {
+    pub fn key(&self) -> &K {
+        &self.hash_table.occupied(self.index).key
+    }
+
+    pub fn get(&self) -> &V {
+        &self.hash_table.occupied(self.index).value
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.hash_table.occupied_mut(self.index).value
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.hash_table.occupied_mut(self.index).value
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    pub fn remove_entry(self) -> (K, V) {
+        let key_value = self.hash_table.remove_at(self.index);
+        (key_value.key, key_value.value)
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher, P: ProbeStep> VacantEntry<'a, K, V, S, P> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        let hash_table = self.hash_table;
+        if let Slot::Deleted = hash_table.data[self.index] {
+            hash_table.deleted -= 1;
+        }
+
+        hash_table.data[self.index] = Slot::Occupied(KeyValue {
+            key: self.key,
+            value,
+        });
+        hash_table.len += 1;
+
+        &mut hash_table.occupied_mut(self.index).value
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    slots: std::slice::Iter<'a, Slot<K, V>>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(key_value) = slot {
+                self.len -= 1;
+                return Some((&key_value.key, &key_value.value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+pub struct IterMut<'a, K, V> {
+    slots: std::slice::IterMut<'a, Slot<K, V>>,
+    len: usize,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(key_value) = slot {
+                self.len -= 1;
+                return Some((&key_value.key, &mut key_value.value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+pub struct ValuesMut<'a, K, V> {
+    inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<&'a mut V> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+pub struct IntoIter<K, V> {
+    slots: std::vec::IntoIter<Slot<K, V>>,
+    len: usize,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(key_value) = slot {
+                self.len -= 1;
+                return Some((key_value.key, key_value.value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+// Empties every slot it walks over, including tombstones, so the table is
+// left clean once the drain is dropped
+pub struct Drain<'a, K, V> {
+    slots: std::slice::IterMut<'a, Slot<K, V>>,
+    len: usize,
+}
+
+impl<K, V> Iterator for Drain<'_, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Occupied(key_value) = std::mem::replace(slot, Slot::Empty) {
+                self.len -= 1;
+                return Some((key_value.key, key_value.value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Drain<'_, K, V> {}
+
+impl<K, V> Drop for Drain<'_, K, V> {
+    fn drop(&mut self) {
+        for slot in self.slots.by_ref() {
+            *slot = Slot::Empty;
+        }
+    }
+}
+
+impl<K, V, S, P> IntoIterator for OpenAddressingTable<K, V, S, P> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            slots: self.data.into_iter(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, K, V, S, P> IntoIterator for &'a OpenAddressingTable<K, V, S, P> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S, P> IntoIterator for &'a mut OpenAddressingTable<K, V, S, P> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, P: ProbeStep> Extend<(K, V)> for OpenAddressingTable<K, V, S, P> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default, P: ProbeStep> FromIterator<(K, V)> for OpenAddressingTable<K, V, S, P> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut hash_table = OpenAddressingTable::with_hasher(S::default());
+        hash_table.extend(iter);
+        hash_table
+    }
+}
+
+// Two tables are equal when they hold the same entries, whatever their
+// capacity or the order of their slots
+impl<K: Hash + Eq, V: PartialEq, S: BuildHasher, P: ProbeStep> PartialEq for OpenAddressingTable<K, V, S, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, S: BuildHasher, P: ProbeStep> Eq for OpenAddressingTable<K, V, S, P> {}
+
+impl<K: fmt::Debug, V: fmt::Debug, S, P> fmt::Debug for OpenAddressingTable<K, V, S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, P> Default for OpenAddressingTable<K, V, DefaultBuildHasher, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::HashTable;
+
+    fn populate_hash_table(hash_table: &mut HashTable<String, i32>, from: i32, to: i32) {
+        for i in from..to {
+            hash_table.insert(format!("key{}", i), i);
+        }
+    }
+
+    #[test]
+    fn tombstones_are_purged() {
+        let mut hash_table: HashTable<String, i32> = HashTable::with_capacity(1000);
+        populate_hash_table(&mut hash_table, 0, 600);
+        let capacity = hash_table.capacity();
+
+        // Churn keys on top of a steady base so tombstones pile up without the table shrinking
+        for round in 1..20 {
+            populate_hash_table(&mut hash_table, round * 1000, round * 1000 + 400);
+            for i in round * 1000..round * 1000 + 400 {
+                let key = format!("key{}", i);
+                assert_eq!(hash_table.remove(&key), Some(i));
+                assert!(hash_table.deleted <= hash_table.capacity() / 4);
+            }
+        }
+
+        assert_eq!(hash_table.capacity(), capacity);
+        assert_eq!(hash_table.len(), 600);
+        for i in 0..600 {
+            let key = format!("key{}", i);
+            assert_eq!(hash_table.get(&key), Some(&i));
+        }
+    }
+
+    #[test]
+    fn entry_for_existing_key_never_resizes() {
+        let mut hash_table: HashTable<i32, i32> = HashTable::new();
+        hash_table.insert(0, 0);
+
+        // Fill the table right up to the point where one more key grows it
+        let mut key = 1;
+        while hash_table.len() < hash_table.threshold() {
+            hash_table.insert(key, key);
+            key += 1;
+        }
+
+        let capacity = hash_table.capacity();
+        for existing in 0..key {
+            *hash_table.entry(existing).or_insert(0) += 1;
+        }
+        assert_eq!(hash_table.capacity(), capacity);
+        assert_eq!(hash_table.get(&0), Some(&1));
+
+        hash_table.entry(key).or_insert(0);
+        assert!(hash_table.capacity() > capacity);
+    }
+
+    #[test]
+    fn drain() {
+        let mut hash_table: HashTable<String, i32> = HashTable::new();
+        populate_hash_table(&mut hash_table, 0, 10);
+        hash_table.remove("key0");
+        let capacity = hash_table.capacity();
+
+        let mut drained: Vec<i32> = hash_table.drain().map(|(_, value)| value).collect();
+        drained.sort();
+        assert_eq!(drained, (1..10).collect::<Vec<i32>>());
+
+        assert!(hash_table.is_empty());
+        assert_eq!(hash_table.capacity(), capacity);
+        assert!(hash_table.data.iter().all(|slot| matches!(slot, Slot::Empty)));
+        assert_eq!(hash_table.get("key5"), None);
+
+        populate_hash_table(&mut hash_table, 0, 3);
+        hash_table.drain().next();
+        assert!(hash_table.is_empty());
+        assert_eq!(hash_table.get("key1"), None);
+    }
+}
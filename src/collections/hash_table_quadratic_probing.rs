@@ -0,0 +1,30 @@
+use super::hash_table_linear_probing::DefaultBuildHasher;
+use super::hash_table_open_addressing::{OpenAddressingTable, ProbeStep};
+
+/// Steps 1, 2, 3, ... slots away from the home slot.
+pub struct Quadratic;
+
+// The steps add up to the triangular numbers, which visit every slot of a
+// power of two table exactly once
+impl ProbeStep for Quadratic {
+    fn step(_hash: u64, probe: usize, _mask: usize) -> usize {
+        probe
+    }
+}
+
+/// Open addressing table that steps 1, 2, 3, ... slots away from the home
+/// slot. Keys that collide spread out quickly instead of piling up into one
+/// run, which avoids the primary clustering of linear probing.
+pub type HashTable<K, V, S = DefaultBuildHasher> = OpenAddressingTable<K, V, S, Quadratic>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::Djb2BuildHasher;
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map_basics(HashTable::new);
+        crate::collections::map::tests::check_map(HashTable::with_hasher(Djb2BuildHasher::default()));
+    }
+}
@@ -0,0 +1,288 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use super::hash_table_linear_probing::{DefaultBuildHasher, KeyValue};
+use super::map::Map;
+
+struct Bucket<K, V> {
+    hash: usize,
+    key_value: KeyValue<K, V>,
+}
+
+/// Linear probing where an insert that has travelled further from its home
+/// slot than the resident entry takes the slot and keeps moving the resident
+/// along instead. This evens out probe lengths, lets a lookup stop as soon as
+/// it passes an entry closer to home than itself, and lets removal shift the
+/// following entries back rather than leaving tombstones.
+pub struct HashTable<K, V, S = DefaultBuildHasher> {
+    data: Vec<Option<Bucket<K, V>>>,
+    len: usize,
+    hash_builder: S,
+}
+
+impl<K, V> HashTable<K, V, DefaultBuildHasher> {
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S> {
+    const MIN_CAPACITY: usize = 8;
+    const MAX_LOAD_FACTOR: f64 = 0.75;
+
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            data: Vec::new(),
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    fn threshold(&self) -> usize {
+        (self.data.len() as f64 * Self::MAX_LOAD_FACTOR) as usize
+    }
+
+    // How far the entry at `index` sits from the slot its hash points to
+    fn distance(&self, index: usize, hash: usize) -> usize {
+        index.wrapping_sub(hash) & (self.data.len() - 1)
+    }
+
+    // Places a bucket known not to be in the table yet, displacing richer
+    // residents on the way
+    fn place(&mut self, mut bucket: Bucket<K, V>) {
+        let mask = self.data.len() - 1;
+        let mut index = bucket.hash & mask;
+        let mut distance = 0;
+
+        loop {
+            match &mut self.data[index] {
+                None => {
+                    self.data[index] = Some(bucket);
+                    return;
+                }
+                Some(resident) => {
+                    let resident_distance = index.wrapping_sub(resident.hash) & mask;
+                    if resident_distance < distance {
+                        std::mem::swap(resident, &mut bucket);
+                        distance = resident_distance;
+                    }
+                }
+            }
+
+            index = (index + 1) & mask;
+            distance += 1;
+        }
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let empty = std::iter::repeat_with(|| None).take(capacity).collect();
+        let old_data = std::mem::replace(&mut self.data, empty);
+
+        for bucket in old_data.into_iter().flatten() {
+            self.place(bucket);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashTable<K, V, S> {
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        self.hash_builder.hash_one(key) as usize
+    }
+
+    // Returns the slot holding `key` and how many slots were inspected
+    fn find<Q>(&self, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let hash = self.hash(key);
+        let mut index = hash & (self.data.len() - 1);
+
+        for distance in 0..self.data.len() {
+            let bucket = self.data[index].as_ref()?;
+
+            // Had the key been inserted, it would have displaced this entry
+            if self.distance(index, bucket.hash) < distance {
+                return None;
+            }
+
+            if bucket.hash == hash && bucket.key_value.key.borrow() == key {
+                return Some((index, distance + 1));
+            }
+
+            index = (index + 1) & (self.data.len() - 1);
+        }
+
+        None
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(existing) = self.get_mut(&key) {
+            return Some(std::mem::replace(existing, value));
+        }
+
+        if self.data.is_empty() {
+            self.resize(Self::MIN_CAPACITY);
+        } else if self.len + 1 > self.threshold() {
+            self.resize(self.data.len() * 2);
+        }
+
+        let hash = self.hash(&key);
+        self.place(Bucket {
+            hash,
+            key_value: KeyValue { key, value },
+        });
+        self.len += 1;
+        None
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find(key)?;
+        self.data[index].as_ref().map(|bucket| &bucket.key_value.value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find(key)?;
+        self.data[index].as_mut().map(|bucket| &mut bucket.key_value.value)
+    }
+
+    pub fn exists<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find(key)?;
+        let removed = self.data[index].take()?;
+        self.len -= 1;
+
+        // Backward shift: pull each following displaced entry one slot closer
+        // to home until reaching a gap or an entry already at home
+        let mut hole = index;
+        loop {
+            let next = (hole + 1) & (self.data.len() - 1);
+            match &self.data[next] {
+                Some(bucket) if self.distance(next, bucket.hash) > 0 => {
+                    self.data[hole] = self.data[next].take();
+                    hole = next;
+                }
+                _ => break,
+            }
+        }
+
+        Some(removed.key_value.value)
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Map<K, V> for HashTable<K, V, S> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashTable::insert(self, key, value)
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::get(self, key)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::get_mut(self, key)
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::remove(self, key)
+    }
+
+    fn probe_length<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|(_, probes)| probes)
+    }
+}
+
+impl<K, V> Default for HashTable<K, V, DefaultBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::Djb2BuildHasher;
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map_basics(HashTable::new);
+        crate::collections::map::tests::check_map(HashTable::with_hasher(Djb2BuildHasher::default()));
+    }
+
+    #[test]
+    fn backward_shift_leaves_no_gaps() {
+        let mut hash_table: HashTable<u64, u64> = HashTable::new();
+        for i in 0..500 {
+            hash_table.insert(i, i);
+        }
+        for i in 0..250 {
+            hash_table.remove(&i);
+        }
+
+        // Every entry must be reachable from its home slot without crossing an empty slot
+        for (index, bucket) in hash_table.data.iter().enumerate() {
+            if let Some(bucket) = bucket {
+                let distance = hash_table.distance(index, bucket.hash);
+                for back in 1..=distance {
+                    let previous = index.wrapping_sub(back) & (hash_table.capacity() - 1);
+                    assert!(hash_table.data[previous].is_some());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use super::hash_table_linear_probing::{DefaultBuildHasher, KeyValue};
+use super::linked_list::LinkedList;
+use super::map::Map;
+
+/// Every bucket holds a linked list of the entries hashing to it, so
+/// collisions only ever lengthen their own chain and removal never needs
+/// tombstones.
+pub struct HashTable<K, V, S = DefaultBuildHasher> {
+    buckets: Vec<LinkedList<KeyValue<K, V>>>,
+    len: usize,
+    hash_builder: S,
+}
+
+impl<K, V> HashTable<K, V, DefaultBuildHasher> {
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S> {
+    const MIN_CAPACITY: usize = 8;
+    // Average chain length allowed before the bucket count doubles
+    const MAX_LOAD_FACTOR: usize = 1;
+
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            buckets: Vec::new(),
+            len: 0,
+            hash_builder,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buckets.len()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashTable<K, V, S> {
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        (self.hash_builder.hash_one(key) as usize) & (self.buckets.len() - 1)
+    }
+
+    fn bucket<Q>(&self, key: &Q) -> Option<&LinkedList<KeyValue<K, V>>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        Some(&self.buckets[self.hash(key)])
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let empty = std::iter::repeat_with(LinkedList::new).take(capacity).collect();
+        let mut old_buckets = std::mem::replace(&mut self.buckets, empty);
+
+        for bucket in old_buckets.iter_mut() {
+            while let Some(key_value) = bucket.pop_front() {
+                let index = self.hash(&key_value.key);
+                self.buckets[index].push_front(key_value);
+            }
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(existing) = self.get_mut(&key) {
+            return Some(std::mem::replace(existing, value));
+        }
+
+        if self.buckets.is_empty() {
+            self.resize(Self::MIN_CAPACITY);
+        } else if self.len + 1 > self.buckets.len() * Self::MAX_LOAD_FACTOR {
+            self.resize(self.buckets.len() * 2);
+        }
+
+        let index = self.hash(&key);
+        self.buckets[index].push_front(KeyValue { key, value });
+        self.len += 1;
+        None
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.bucket(key)?
+            .iter()
+            .find(|key_value| key_value.key.borrow() == key)
+            .map(|key_value| &key_value.value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let index = self.hash(key);
+        self.buckets[index]
+            .iter_mut()
+            .find(|key_value| key_value.key.borrow() == key)
+            .map(|key_value| &mut key_value.value)
+    }
+
+    pub fn exists<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let position = self.bucket(key)?.iter().position(|key_value| key_value.key.borrow() == key)?;
+
+        let index = self.hash(key);
+        self.len -= 1;
+        Some(self.buckets[index].remove(position).value)
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Map<K, V> for HashTable<K, V, S> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashTable::insert(self, key, value)
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::get(self, key)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::get_mut(self, key)
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::remove(self, key)
+    }
+
+    // Chain nodes compared before reaching the key
+    fn probe_length<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.bucket(key)?
+            .iter()
+            .position(|key_value| key_value.key.borrow() == key)
+            .map(|position| position + 1)
+    }
+}
+
+impl<K, V> Default for HashTable<K, V, DefaultBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::Djb2BuildHasher;
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map_basics(HashTable::new);
+        crate::collections::map::tests::check_map(HashTable::with_hasher(Djb2BuildHasher::default()));
+    }
+
+    #[test]
+    fn probe_length_is_chain_position() {
+        let mut hash_table: HashTable<u64, u64> = HashTable::new();
+        for i in 0..100 {
+            hash_table.insert(i, i);
+        }
+
+        let longest = hash_table.buckets.iter().map(|bucket| bucket.len()).max().unwrap();
+        let deepest = (0..100).map(|i| hash_table.probe_length(&i).unwrap()).max().unwrap();
+        assert_eq!(longest, deepest);
+    }
+}
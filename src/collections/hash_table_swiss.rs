@@ -0,0 +1,622 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+use std::mem::MaybeUninit;
+
+use super::hash_table_linear_probing::{DefaultBuildHasher, KeyValue};
+use super::map::Map;
+
+use group::{Group, GROUP_WIDTH};
+
+// Every slot has one control byte. A full slot stores the top 7 bits of its
+// key's hash, so most mismatches are rejected without touching the key.
+const EMPTY: u8 = 0b1111_1111;
+const DELETED: u8 = 0b1000_0000;
+
+mod group {
+    pub const GROUP_WIDTH: usize = 16;
+
+    /// Set bits mark the bytes of a group that matched. Each matching byte
+    /// sets one bit `stride` bits wide, so the byte index is the bit index
+    /// divided by `stride`.
+    #[derive(Clone, Copy)]
+    pub struct BitMask {
+        bits: u128,
+        stride: u32,
+    }
+
+    impl BitMask {
+        pub fn any(self) -> bool {
+            self.bits != 0
+        }
+
+        pub fn lowest(self) -> Option<usize> {
+            if self.bits == 0 {
+                None
+            } else {
+                Some((self.bits.trailing_zeros() / self.stride) as usize)
+            }
+        }
+    }
+
+    impl Iterator for BitMask {
+        type Item = usize;
+
+        fn next(&mut self) -> Option<usize> {
+            let index = self.lowest()?;
+            self.bits &= self.bits - 1;
+            Some(index)
+        }
+    }
+
+    // Only the implementation in use is built, except under test where both
+    // are checked against each other
+
+    /// Portable implementation working on the 16 control bytes as one u128.
+    #[cfg(any(test, not(all(feature = "sse2", target_arch = "x86_64", target_feature = "sse2"))))]
+    pub mod generic {
+        use super::{BitMask, GROUP_WIDTH};
+        use crate::collections::hash_table_swiss::EMPTY;
+
+        const LOW_BITS: u128 = u128::from_le_bytes([0x01; GROUP_WIDTH]);
+        const HIGH_BITS: u128 = u128::from_le_bytes([0x80; GROUP_WIDTH]);
+
+        #[derive(Clone, Copy)]
+        pub struct Group(u128);
+
+        impl Group {
+            pub fn load(ctrl: &[u8]) -> Self {
+                let bytes: [u8; GROUP_WIDTH] = ctrl[..GROUP_WIDTH].try_into().unwrap();
+                Group(u128::from_le_bytes(bytes))
+            }
+
+            // Classic "has zero byte" trick on the group xored with `byte`.
+            // A borrow can flag a byte right after a real match, which is
+            // harmless since candidates are compared by key anyway.
+            pub fn match_byte(self, byte: u8) -> BitMask {
+                let cmp = self.0 ^ (LOW_BITS * byte as u128);
+                BitMask {
+                    bits: cmp.wrapping_sub(LOW_BITS) & !cmp & HIGH_BITS,
+                    stride: 8,
+                }
+            }
+
+            // Only EMPTY has both of its two top bits set
+            pub fn match_empty(self) -> BitMask {
+                debug_assert_eq!(EMPTY & 0xc0, 0xc0);
+                BitMask {
+                    bits: self.0 & (self.0 << 1) & HIGH_BITS,
+                    stride: 8,
+                }
+            }
+
+            // Both EMPTY and DELETED have the top bit set, full slots don't
+            pub fn match_empty_or_deleted(self) -> BitMask {
+                BitMask {
+                    bits: self.0 & HIGH_BITS,
+                    stride: 8,
+                }
+            }
+        }
+    }
+
+    /// SSE2 implementation comparing all 16 control bytes in one instruction.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2", any(test, feature = "sse2")))]
+    pub mod sse2 {
+        use std::arch::x86_64::{__m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8};
+
+        use super::{BitMask, GROUP_WIDTH};
+
+        #[derive(Clone, Copy)]
+        pub struct Group(__m128i);
+
+        // SSE2 is part of the x86_64 baseline, so the intrinsics are always
+        // available even though they are declared unsafe
+        impl Group {
+            pub fn load(ctrl: &[u8]) -> Self {
+                assert!(ctrl.len() >= GROUP_WIDTH);
+                Group(unsafe { _mm_loadu_si128(ctrl.as_ptr() as *const __m128i) })
+            }
+
+            pub fn match_byte(self, byte: u8) -> BitMask {
+                let mask = unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(self.0, _mm_set1_epi8(byte as i8))) };
+                BitMask {
+                    bits: mask as u16 as u128,
+                    stride: 1,
+                }
+            }
+
+            pub fn match_empty(self) -> BitMask {
+                self.match_byte(super::super::EMPTY)
+            }
+
+            pub fn match_empty_or_deleted(self) -> BitMask {
+                let mask = unsafe { _mm_movemask_epi8(self.0) };
+                BitMask {
+                    bits: mask as u16 as u128,
+                    stride: 1,
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "sse2", target_arch = "x86_64", target_feature = "sse2"))]
+    pub use sse2::Group;
+
+    #[cfg(not(all(feature = "sse2", target_arch = "x86_64", target_feature = "sse2")))]
+    pub use generic::Group;
+}
+
+/// Open addressing table in the style of Abseil's Swiss tables.
+///
+/// Control bytes live in their own array, apart from the entries, and are
+/// scanned 16 at a time. A lookup usually touches one cache line of control
+/// bytes and then only the entries whose 7-bit hash fragment matches.
+pub struct HashTable<K, V, S = DefaultBuildHasher> {
+    // One byte per slot, followed by a copy of the first GROUP_WIDTH bytes so
+    // a group starting near the end can be loaded without wrapping
+    ctrl: Vec<u8>,
+    slots: Vec<MaybeUninit<KeyValue<K, V>>>,
+    len: usize,
+    // Inserts into empty slots left before the table must grow. Reusing a
+    // deleted slot doesn't use any up.
+    growth_left: usize,
+    hash_builder: S,
+}
+
+impl<K, V> HashTable<K, V, DefaultBuildHasher> {
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, DefaultBuildHasher::default())
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S> {
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            ctrl: Vec::new(),
+            slots: Vec::new(),
+            len: 0,
+            growth_left: 0,
+            hash_builder,
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut hash_table = Self::with_hasher(hash_builder);
+        if capacity > 0 {
+            let buckets = Self::buckets_for(capacity);
+            hash_table.ctrl = vec![EMPTY; buckets + GROUP_WIDTH];
+            hash_table.slots = Self::uninit_slots(buckets);
+            hash_table.growth_left = Self::max_load(buckets);
+        }
+
+        hash_table
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.slots
+            .iter()
+            .zip(self.ctrl.iter())
+            .filter(|(_, &ctrl)| is_full(ctrl))
+            .map(|(slot, _)| {
+                let key_value = unsafe { slot.assume_init_ref() };
+                (&key_value.key, &key_value.value)
+            })
+    }
+
+    // The table is never more than 7/8 full, so probing always finds an empty slot
+    fn max_load(buckets: usize) -> usize {
+        buckets - buckets / 8
+    }
+
+    // Buckets are a power of two and never smaller than a group, which keeps
+    // the mirrored control bytes and the probe sequence simple
+    fn buckets_for(entries: usize) -> usize {
+        let buckets = entries.checked_mul(8).expect("capacity overflow") / 7 + 1;
+        buckets.max(GROUP_WIDTH).checked_next_power_of_two().expect("capacity overflow")
+    }
+
+    fn uninit_slots(buckets: usize) -> Vec<MaybeUninit<KeyValue<K, V>>> {
+        std::iter::repeat_with(MaybeUninit::uninit).take(buckets).collect()
+    }
+
+    fn set_ctrl(&mut self, index: usize, value: u8) {
+        set_ctrl(&mut self.ctrl, self.slots.len() - 1, index, value);
+    }
+}
+
+fn is_full(ctrl: u8) -> bool {
+    ctrl & 0x80 == 0
+}
+
+fn set_ctrl(ctrl: &mut [u8], mask: usize, index: usize, value: u8) {
+    ctrl[index] = value;
+    // Slots in the first group are also written to their mirror
+    ctrl[(index.wrapping_sub(GROUP_WIDTH) & mask) + GROUP_WIDTH] = value;
+}
+
+fn find_insert_slot(ctrl: &[u8], mask: usize, hash: u64) -> usize {
+    let mut probe = ProbeSequence::new(hash, mask);
+
+    loop {
+        let group = Group::load(&ctrl[probe.pos..]);
+        if let Some(bit) = group.match_empty_or_deleted().lowest() {
+            return (probe.pos + bit) & mask;
+        }
+
+        probe.advance();
+    }
+}
+
+// The low bits pick where probing starts and the top 7 go in the control byte
+fn h1(hash: u64) -> usize {
+    hash as usize
+}
+
+fn h2(hash: u64) -> u8 {
+    (hash >> 57) as u8
+}
+
+// Groups are visited at triangular offsets, which in a power of two table
+// reaches every group position before repeating
+struct ProbeSequence {
+    pos: usize,
+    stride: usize,
+    mask: usize,
+}
+
+impl ProbeSequence {
+    fn new(hash: u64, mask: usize) -> Self {
+        Self {
+            pos: h1(hash) & mask,
+            stride: 0,
+            mask,
+        }
+    }
+
+    fn advance(&mut self) {
+        self.stride += GROUP_WIDTH;
+        self.pos = (self.pos + self.stride) & self.mask;
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashTable<K, V, S> {
+    // Returns the slot holding `key` and the number of groups scanned to reach it
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        if self.slots.is_empty() {
+            return None;
+        }
+
+        let mask = self.slots.len() - 1;
+        let mut probe = ProbeSequence::new(hash, mask);
+        let mut groups = 1;
+
+        loop {
+            let group = Group::load(&self.ctrl[probe.pos..]);
+
+            for bit in group.match_byte(h2(hash)) {
+                let index = (probe.pos + bit) & mask;
+                if is_full(self.ctrl[index]) && unsafe { self.slots[index].assume_init_ref() }.key.borrow() == key {
+                    return Some((index, groups));
+                }
+            }
+
+            if group.match_empty().any() {
+                return None;
+            }
+
+            probe.advance();
+            groups += 1;
+        }
+    }
+
+    fn find_insert_slot(&self, hash: u64) -> usize {
+        find_insert_slot(&self.ctrl, self.slots.len() - 1, hash)
+    }
+
+    // Moves every entry into a table of `buckets` slots. `self` isn't touched
+    // until the end, so a panicking hasher leaks entries instead of dropping
+    // them twice.
+    fn resize(&mut self, buckets: usize) {
+        let mut ctrl = vec![EMPTY; buckets + GROUP_WIDTH];
+        let mut slots = Self::uninit_slots(buckets);
+        let mask = buckets - 1;
+
+        for index in 0..self.slots.len() {
+            if !is_full(self.ctrl[index]) {
+                continue;
+            }
+
+            let key_value = unsafe { self.slots[index].assume_init_ref() };
+            let hash = self.hash_builder.hash_one(&key_value.key);
+            let new_index = find_insert_slot(&ctrl, mask, hash);
+
+            set_ctrl(&mut ctrl, mask, new_index, h2(hash));
+            slots[new_index].write(unsafe { std::ptr::read(key_value) });
+        }
+
+        self.ctrl = ctrl;
+        // The old slots are MaybeUninit, so dropping them doesn't drop the moved entries
+        self.slots = slots;
+        self.growth_left = Self::max_load(buckets) - self.len;
+    }
+
+    fn reserve_one(&mut self) {
+        if self.growth_left > 0 {
+            return;
+        }
+
+        if self.slots.is_empty() {
+            self.resize(GROUP_WIDTH);
+        } else if self.len < Self::max_load(self.slots.len()) / 2 {
+            // Mostly tombstones, clearing them out frees enough room
+            self.resize(self.slots.len());
+        } else {
+            self.resize(self.slots.len() * 2);
+        }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.len + self.growth_left {
+            self.resize(Self::buckets_for(required));
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hash_builder.hash_one(&key);
+
+        if let Some((index, _)) = self.find(hash, &key) {
+            let key_value = unsafe { self.slots[index].assume_init_mut() };
+            return Some(std::mem::replace(&mut key_value.value, value));
+        }
+
+        self.reserve_one();
+
+        let index = self.find_insert_slot(hash);
+        if self.ctrl[index] == EMPTY {
+            self.growth_left -= 1;
+        }
+
+        self.set_ctrl(index, h2(hash));
+        self.slots[index].write(KeyValue { key, value });
+        self.len += 1;
+        None
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find(self.hash_builder.hash_one(key), key)?;
+        Some(unsafe { &self.slots[index].assume_init_ref().value })
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find(self.hash_builder.hash_one(key), key)?;
+        Some(unsafe { &mut self.slots[index].assume_init_mut().value })
+    }
+
+    pub fn exists<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(self.hash_builder.hash_one(key), key).is_some()
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find(self.hash_builder.hash_one(key), key)?;
+
+        self.set_ctrl(index, DELETED);
+        self.len -= 1;
+        let key_value = unsafe { self.slots[index].assume_init_read() };
+        Some(key_value.value)
+    }
+}
+
+impl<K, V, S> Drop for HashTable<K, V, S> {
+    fn drop(&mut self) {
+        for (slot, &ctrl) in self.slots.iter_mut().zip(self.ctrl.iter()) {
+            if is_full(ctrl) {
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Map<K, V> for HashTable<K, V, S> {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashTable::insert(self, key, value)
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::get(self, key)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::get_mut(self, key)
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        HashTable::remove(self, key)
+    }
+
+    // Groups of 16 control bytes scanned, since each scan is a single compare
+    fn probe_length<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(self.hash_builder.hash_one(key), key).map(|(_, groups)| groups)
+    }
+}
+
+impl<K, V> Default for HashTable<K, V, DefaultBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::Djb2BuildHasher;
+
+    #[test]
+    fn with_capacity_and_reserve() {
+        let mut hash_table: HashTable<u64, u64> = HashTable::with_capacity(1000);
+        let capacity = hash_table.capacity();
+        for i in 0..1000 {
+            hash_table.insert(i, i);
+        }
+        assert_eq!(hash_table.capacity(), capacity);
+
+        hash_table.reserve(10_000);
+        let capacity = hash_table.capacity();
+        for i in 1000..11_000 {
+            hash_table.insert(i, i);
+        }
+        assert_eq!(hash_table.capacity(), capacity);
+    }
+
+    #[test]
+    fn tombstones_are_reclaimed_in_place() {
+        let mut hash_table: HashTable<u64, u64> = HashTable::with_capacity(100);
+        let capacity = hash_table.capacity();
+
+        for i in 0..10_000 {
+            hash_table.insert(i, i);
+            assert_eq!(hash_table.remove(&i), Some(i));
+        }
+
+        assert!(hash_table.is_empty());
+        assert_eq!(hash_table.capacity(), capacity);
+    }
+
+    #[test]
+    fn drops_every_entry_once() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut hash_table = HashTable::new();
+        for i in 0..1000 {
+            hash_table.insert(i, Rc::clone(&value));
+        }
+        for i in 0..500 {
+            hash_table.remove(&i);
+        }
+        hash_table.insert(0, Rc::clone(&value));
+        assert_eq!(Rc::strong_count(&value), 502);
+
+        drop(hash_table);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn iter() {
+        let mut hash_table: HashTable<u64, u64> = HashTable::new();
+        for i in 0..100 {
+            hash_table.insert(i, i);
+        }
+        hash_table.remove(&0);
+
+        assert_eq!(hash_table.iter().count(), 99);
+        assert_eq!(hash_table.iter().map(|(_, value)| value).sum::<u64>(), 4950);
+    }
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map_basics(HashTable::new);
+        crate::collections::map::tests::check_map(HashTable::with_hasher(Djb2BuildHasher::default()));
+    }
+
+    #[test]
+    fn generic_group_matches() {
+        let mut ctrl = [EMPTY; GROUP_WIDTH];
+        ctrl[1] = 0x12;
+        ctrl[4] = DELETED;
+        ctrl[7] = 0x12;
+        ctrl[9] = 0x7f;
+
+        let group = group::generic::Group::load(&ctrl);
+        assert_eq!(group.match_byte(0x12).collect::<Vec<usize>>(), vec![1, 7]);
+        assert_eq!(group.match_byte(0x7f).collect::<Vec<usize>>(), vec![9]);
+        assert_eq!(group.match_empty().count(), GROUP_WIDTH - 4);
+        assert_eq!(group.match_empty_or_deleted().count(), GROUP_WIDTH - 3);
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    #[test]
+    fn sse2_group_agrees_with_generic() {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..1000 {
+            let mut ctrl = [0u8; GROUP_WIDTH];
+            for byte in ctrl.iter_mut() {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                *byte = match state % 4 {
+                    0 => EMPTY,
+                    1 => DELETED,
+                    _ => (state >> 8) as u8 & 0x7f,
+                };
+            }
+
+            let generic = group::generic::Group::load(&ctrl);
+            let sse2 = group::sse2::Group::load(&ctrl);
+            assert!(generic.match_empty().eq(sse2.match_empty()));
+            assert!(generic.match_empty_or_deleted().eq(sse2.match_empty_or_deleted()));
+
+            // The portable match may report extra candidates, never fewer
+            let byte = ctrl[(state % GROUP_WIDTH as u64) as usize] & 0x7f;
+            let exact: Vec<usize> = sse2.match_byte(byte).collect();
+            let candidates: Vec<usize> = generic.match_byte(byte).collect();
+            assert!(exact.iter().all(|index| candidates.contains(index)));
+        }
+    }
+}
@@ -0,0 +1,763 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
+
+use super::hash_table_linear_probing::DefaultBuildHasher;
+use super::map::Map;
+use super::vector::{self, Vector};
+
+struct Bucket<K, V> {
+    hash: usize,
+    key: K,
+    value: V,
+}
+
+/// A hash map that remembers insertion order. Entries live densely in a
+/// `Vector` in the order they were added, and a linear probing table of
+/// indices into it finds them by key. Iteration walks the `Vector`, so it is
+/// deterministic and as fast as iterating a slice.
+pub struct IndexMap<K, V, S = DefaultBuildHasher> {
+    entries: Vector<Bucket<K, V>>,
+    indices: Vec<Option<usize>>,
+    hash_builder: S,
+}
+
+impl<K, V> IndexMap<K, V, DefaultBuildHasher> {
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, DefaultBuildHasher::default())
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S> {
+    const MIN_CAPACITY: usize = 8;
+    const MAX_LOAD_FACTOR: f64 = 0.75;
+
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            entries: Vector::new(),
+            indices: Vec::new(),
+            hash_builder,
+        }
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut map = Self::with_hasher(hash_builder);
+        if capacity > 0 {
+            map.entries.reserve_exact(capacity);
+            map.rebuild(Self::slots_for(capacity));
+        }
+        map
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|bucket| (&bucket.key, &bucket.value))
+    }
+
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries.get_mut(index).map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|bucket| (&bucket.key, &bucket.value))
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.entries.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.entries.iter_mut(),
+        }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { iter: self.iter_mut() }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.indices.iter_mut().for_each(|slot| *slot = None);
+    }
+
+    /// Reorders the entries with `compare` and keeps that order from then on.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.entries.sort_by(|a, b| compare(&a.key, &a.value, &b.key, &b.value));
+        // A map that never allocated its slots has nothing to point at
+        if !self.indices.is_empty() {
+            self.rebuild(self.indices.len());
+        }
+    }
+
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.sort_by(|a, _, b, _| a.cmp(b));
+    }
+
+    fn slots_for(entries: usize) -> usize {
+        let slots = (entries as f64 / Self::MAX_LOAD_FACTOR).ceil() as usize + 1;
+        slots.next_power_of_two().max(Self::MIN_CAPACITY)
+    }
+
+    fn threshold(&self) -> usize {
+        (self.indices.len() as f64 * Self::MAX_LOAD_FACTOR) as usize
+    }
+
+    // Points `capacity` fresh slots at the entries. The hashes are stored with
+    // the entries, so nothing is rehashed.
+    fn rebuild(&mut self, capacity: usize) {
+        self.indices = vec![None; capacity];
+
+        let mask = capacity - 1;
+        for (index, bucket) in self.entries.iter().enumerate() {
+            let mut slot = bucket.hash & mask;
+            while self.indices[slot].is_some() {
+                slot = (slot + 1) & mask;
+            }
+            self.indices[slot] = Some(index);
+        }
+    }
+
+    // Finds the slot pointing at the entry `index`, whose hash is `hash`
+    fn slot_of(&self, hash: usize, index: usize) -> usize {
+        let mask = self.indices.len() - 1;
+        let mut slot = hash & mask;
+        while self.indices[slot] != Some(index) {
+            slot = (slot + 1) & mask;
+        }
+        slot
+    }
+
+    // Empties `slot`, then shifts back every following index that would
+    // otherwise become unreachable from its home slot
+    fn erase_slot(&mut self, slot: usize) {
+        let mask = self.indices.len() - 1;
+        self.indices[slot] = None;
+
+        let mut hole = slot;
+        let mut next = (slot + 1) & mask;
+        while let Some(index) = self.indices[next] {
+            let home = self.entries[index].hash & mask;
+
+            // The index may fill the hole unless its home lies after the hole
+            if next.wrapping_sub(home) & mask >= next.wrapping_sub(hole) & mask {
+                self.indices[hole] = self.indices[next].take();
+                hole = next;
+            }
+            next = (next + 1) & mask;
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let hash = self.entries.last()?.hash;
+        let slot = self.slot_of(hash, self.entries.len() - 1);
+        self.erase_slot(slot);
+        self.entries.pop().map(|bucket| (bucket.key, bucket.value))
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> IndexMap<K, V, S> {
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        self.hash_builder.hash_one(key) as usize
+    }
+
+    // Returns the slot pointing at `key` and how many slots were inspected
+    fn find<Q>(&self, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.indices.is_empty() {
+            return None;
+        }
+
+        let hash = self.hash(key);
+        let mask = self.indices.len() - 1;
+        let mut slot = hash & mask;
+
+        for probes in 1..=self.indices.len() {
+            let bucket = &self.entries[self.indices[slot]?];
+            if bucket.hash == hash && bucket.key.borrow() == key {
+                return Some((slot, probes));
+            }
+            slot = (slot + 1) & mask;
+        }
+
+        None
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.entries.len() + additional;
+        self.entries.reserve(additional);
+        if needed > self.threshold() {
+            self.rebuild(Self::slots_for(needed));
+        }
+    }
+
+    /// Inserts or updates `key`. An updated key keeps its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Like `insert`, but also returns the position of the entry.
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        if let Some(index) = self.get_index_of(&key) {
+            let old = std::mem::replace(&mut self.entries[index].value, value);
+            return (index, Some(old));
+        }
+
+        if self.indices.is_empty() {
+            self.rebuild(Self::MIN_CAPACITY);
+        } else if self.entries.len() + 1 > self.threshold() {
+            self.rebuild(self.indices.len() * 2);
+        }
+
+        let hash = self.hash(&key);
+        let mask = self.indices.len() - 1;
+        let mut slot = hash & mask;
+        while self.indices[slot].is_some() {
+            slot = (slot + 1) & mask;
+        }
+
+        let index = self.entries.len();
+        self.indices[slot] = Some(index);
+        self.entries.push(Bucket { hash, key, value });
+        (index, None)
+    }
+
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).and_then(|(slot, _)| self.indices[slot])
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        self.get_index(index)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(&mut self.entries[index].value)
+    }
+
+    pub fn exists<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Removes `key` in O(1) by moving the last entry into its position,
+    /// which changes the order of the remaining entries.
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (slot, _) = self.find(key)?;
+        let index = self.indices[slot]?;
+        self.erase_slot(slot);
+
+        let last = self.entries.len() - 1;
+        if index != last {
+            let moved = self.slot_of(self.entries[last].hash, last);
+            self.indices[moved] = Some(index);
+            self.entries.swap(index, last);
+        }
+
+        self.entries.pop().map(|bucket| bucket.value)
+    }
+
+    /// Removes `key` and shifts the following entries down, keeping their
+    /// order. This is O(n).
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (slot, _) = self.find(key)?;
+        let index = self.indices[slot]?;
+        self.erase_slot(slot);
+
+        for position in self.indices.iter_mut().flatten() {
+            if *position > index {
+                *position -= 1;
+            }
+        }
+
+        Some(self.entries.remove(index).value)
+    }
+
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        let before = self.entries.len();
+
+        // Kept entries are swapped down past the rejected ones, which keeps
+        // their order and leaves the rejected ones at the end to truncate
+        let mut kept = 0;
+        for index in 0..before {
+            let bucket = &mut self.entries[index];
+            if f(&bucket.key, &mut bucket.value) {
+                self.entries.swap(kept, index);
+                kept += 1;
+            }
+        }
+
+        if kept != before {
+            self.entries.truncate(kept);
+            self.rebuild(self.indices.len());
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Map<K, V> for IndexMap<K, V, S> {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        IndexMap::insert(self, key, value)
+    }
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        IndexMap::get(self, key)
+    }
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        IndexMap::get_mut(self, key)
+    }
+
+    // Removal through the trait keeps the insertion order intact
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        IndexMap::shift_remove(self, key)
+    }
+
+    fn probe_length<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).map(|(_, probes)| probes)
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    iter: std::slice::Iter<'a, Bucket<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|bucket| (&bucket.key, &bucket.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|bucket| (&bucket.key, &bucket.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+pub struct IterMut<'a, K, V> {
+    iter: std::slice::IterMut<'a, Bucket<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+pub struct ValuesMut<'a, K, V> {
+    iter: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<&'a mut V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for ValuesMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+pub struct IntoIter<K, V> {
+    iter: vector::IntoIter<Bucket<K, V>>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.iter.next().map(|bucket| (bucket.key, bucket.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.iter.next_back().map(|bucket| (bucket.key, bucket.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V, S> IntoIterator for IndexMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            iter: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a IndexMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut IndexMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for IndexMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for IndexMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = IndexMap::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+// Two maps are equal only if they hold the same entries in the same order
+impl<K: PartialEq, V: PartialEq, S> PartialEq for IndexMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq, S> Eq for IndexMap<K, V, S> {}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for IndexMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> Default for IndexMap<K, V, DefaultBuildHasher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::hash_table_linear_probing::Djb2BuildHasher;
+
+    fn keys<S>(map: &IndexMap<String, i32, S>) -> Vec<&str> {
+        map.keys().map(|key| key.as_str()).collect()
+    }
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let mut map = IndexMap::new();
+        for key in ["zeta", "alpha", "mid", "beta"] {
+            map.insert(key.to_string(), key.len() as i32);
+        }
+
+        assert_eq!(keys(&map), vec!["zeta", "alpha", "mid", "beta"]);
+        assert_eq!(map.insert("alpha".to_string(), 0), Some(5));
+        assert_eq!(keys(&map), vec!["zeta", "alpha", "mid", "beta"]);
+        assert_eq!(map.get("alpha"), Some(&0));
+    }
+
+    #[test]
+    fn get_index() {
+        let map: IndexMap<String, i32> = (0..100).map(|i| (format!("key{}", i), i)).collect();
+
+        for i in 0..100 {
+            assert_eq!(map.get_index(i as usize), Some((&format!("key{}", i), &i)));
+            assert_eq!(map.get_index_of(&format!("key{}", i)), Some(i as usize));
+        }
+        assert_eq!(map.get_index(100), None);
+        assert_eq!(map.first(), Some((&"key0".to_string(), &0)));
+        assert_eq!(map.last(), Some((&"key99".to_string(), &99)));
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut map: IndexMap<String, i32> = ["a", "b", "c", "d"].iter().map(|key| (key.to_string(), 0)).collect();
+
+        assert_eq!(map.swap_remove("b"), Some(0));
+        assert_eq!(keys(&map), vec!["a", "d", "c"]);
+        assert_eq!(map.get_index_of("d"), Some(1));
+
+        assert_eq!(map.swap_remove("c"), Some(0));
+        assert_eq!(map.swap_remove("c"), None);
+        assert_eq!(keys(&map), vec!["a", "d"]);
+    }
+
+    #[test]
+    fn shift_remove() {
+        let mut map: IndexMap<String, i32> = ["a", "b", "c", "d"].iter().map(|key| (key.to_string(), 0)).collect();
+
+        assert_eq!(map.shift_remove("b"), Some(0));
+        assert_eq!(keys(&map), vec!["a", "c", "d"]);
+        assert_eq!(map.get_index_of("c"), Some(1));
+        assert_eq!(map.get_index_of("d"), Some(2));
+        assert_eq!(map.shift_remove("b"), None);
+    }
+
+    #[test]
+    fn removal_keeps_every_key_reachable() {
+        let mut map: IndexMap<u64, u64> = (0..1000).map(|i| (i, i)).collect();
+        for i in (0..1000).step_by(3) {
+            assert_eq!(map.swap_remove(&i), Some(i));
+        }
+        for i in (1..1000).step_by(3) {
+            assert_eq!(map.shift_remove(&i), Some(i));
+        }
+
+        assert_eq!(map.len(), 333);
+        for i in (2..1000).step_by(3) {
+            assert_eq!(map.get(&i), Some(&i));
+            let index = map.get_index_of(&i).unwrap();
+            assert_eq!(map.get_index(index), Some((&i, &i)));
+        }
+    }
+
+    #[test]
+    fn sort_keys() {
+        let mut map: IndexMap<String, i32> = ["c", "a", "d", "b"].iter().map(|key| (key.to_string(), 0)).collect();
+        map.sort_keys();
+
+        assert_eq!(keys(&map), vec!["a", "b", "c", "d"]);
+        assert_eq!(map.get_index_of("c"), Some(2));
+
+        map.sort_by(|a, _, b, _| b.cmp(a));
+        assert_eq!(keys(&map), vec!["d", "c", "b", "a"]);
+        assert_eq!(map.get_index_of("c"), Some(1));
+    }
+
+    #[test]
+    fn sort_keys_on_empty_map() {
+        let mut map: IndexMap<String, i32> = IndexMap::new();
+        map.sort_keys();
+        assert!(map.is_empty());
+
+        let mut map: IndexMap<String, i32> = IndexMap::with_capacity(0);
+        map.sort_by(|a, _, b, _| b.cmp(a));
+        map.insert("a".to_string(), 1);
+        assert_eq!(map.get("a"), Some(&1));
+    }
+
+    #[test]
+    fn pop_and_retain() {
+        let mut map: IndexMap<u64, u64> = (0..10).map(|i| (i, i)).collect();
+
+        assert_eq!(map.pop(), Some((9, 9)));
+        map.retain(|key, value| {
+            *value *= 10;
+            key % 2 == 0
+        });
+
+        assert_eq!(map.iter().map(|(&k, &v)| (k, v)).collect::<Vec<_>>(), vec![(0, 0), (2, 20), (4, 40), (6, 60), (8, 80)]);
+        assert!(!map.exists(&1));
+        assert_eq!(map.get(&8), Some(&80));
+    }
+
+    #[test]
+    fn into_iter_and_eq() {
+        let map: IndexMap<u64, u64> = (0..5).rev().map(|i| (i, i)).collect();
+        let reversed: IndexMap<u64, u64> = (0..5).map(|i| (i, i)).collect();
+
+        assert_ne!(map, reversed);
+        assert_eq!(map.into_iter().map(|(key, _)| key).collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+        assert_eq!(format!("{:?}", reversed), "{0: 0, 1: 1, 2: 2, 3: 3, 4: 4}");
+    }
+
+    #[test]
+    fn custom_hasher() {
+        let mut map: IndexMap<u64, u64, Djb2BuildHasher> = IndexMap::with_hasher(Djb2BuildHasher::default());
+        for i in 0..100 {
+            map.insert(i, i * i);
+        }
+
+        for i in 0..100 {
+            assert_eq!(map.get(&i), Some(&(i * i)));
+        }
+    }
+
+    #[test]
+    fn map_trait() {
+        crate::collections::map::tests::check_map(IndexMap::new());
+    }
+}
@@ -0,0 +1,1070 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::allocator::{box_in, unbox_in, Allocator, Global};
+use crate::collections::error::{IndexOutOfBounds, InsertError};
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+pub struct LinkedList<T, A: Allocator = Global> {
+    head: Link<T>,
+    len: usize,
+    alloc: A,
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> LinkedList<T> {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> LinkedList<T, A> {
+    pub fn new_in(alloc: A) -> LinkedList<T, A> {
+        Self { 
+            head: None,
+            len: 0,
+            alloc,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = box_in(Node {
+            value,
+            next: self.head.take(),
+        }, &self.alloc);
+
+        self.head = Some(node);
+        self.len += 1;
+    }
+    pub fn push_back(&mut self, value: T) {
+        if self.is_empty() {
+            self.push_front(value);
+            return;
+        }
+
+        let new_node = box_in(Node {
+            value,
+            next: None,
+        }, &self.alloc);
+
+        let mut current = self.head.unwrap();
+        unsafe {
+            while let Some(next) = current.as_ref().next {
+                current = next;
+            }
+
+            current.as_mut().next = Some(new_node);
+        }
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let node = unsafe { unbox_in(self.head.take().unwrap(), &self.alloc) };
+        self.head = node.next;
+        self.len -= 1;
+
+        Some(node.value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        if unsafe { self.head.unwrap().as_ref().next.is_none() } {
+            return self.pop_front();
+        }
+
+        let mut current = self.head.unwrap();
+        unsafe {
+            while current.as_ref().next.unwrap().as_ref().next.is_some() {
+                current = current.as_ref().next.unwrap();
+            }
+        }
+
+        let node = unsafe { unbox_in(current.as_mut().next.take().unwrap(), &self.alloc) };
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut current = self.head.unwrap();
+        unsafe {
+            while let Some(next) = current.as_ref().next {
+                current = next;
+            }
+
+            Some(&(*current.as_ptr()).value)
+        }
+    }
+
+    // The node at `position`, or `None` past the end
+    fn node_at(&self, position: usize) -> Link<T> {
+        let mut current = self.head;
+        for _ in 0..position {
+            current = unsafe { current?.as_ref().next };
+        }
+        current
+    }
+
+    pub fn get(&self, position: usize) -> Option<&T> {
+        self.node_at(position).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn get_mut(&mut self, position: usize) -> Option<&mut T> {
+        self.node_at(position).map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn insert(&mut self, position: usize, value: T) {
+        if let Err(error) = self.try_insert(position, value) {
+            panic!("Insertion index ({}) is out of bounds len ({})", error.error.index, error.error.len);
+        }
+    }
+
+    /// Inserts `value` so it ends up at `position`, or hands it back if
+    /// `position` is past the end.
+    pub fn try_insert(&mut self, position: usize, value: T) -> Result<(), InsertError<T>> {
+        if position > self.len {
+            return Err(InsertError {
+                error: IndexOutOfBounds { index: position, len: self.len },
+                value,
+            });
+        }
+
+        if position == 0 {
+            self.push_front(value);
+            return Ok(());
+        }
+
+        let mut prev = self.node_at(position - 1).unwrap();
+        unsafe {
+            let node = box_in(Node { value, next: prev.as_ref().next }, &self.alloc);
+            prev.as_mut().next = Some(node);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, position: usize) -> T {
+        match self.try_remove(position) {
+            Ok(value) => value,
+            Err(error) => panic!("Removal index ({}) is out of bounds len ({})", error.index, error.len),
+        }
+    }
+
+    pub fn try_remove(&mut self, position: usize) -> Result<T, IndexOutOfBounds> {
+        if position >= self.len {
+            return Err(IndexOutOfBounds { index: position, len: self.len });
+        }
+
+        if position == 0 {
+            return Ok(self.pop_front().unwrap());
+        }
+
+        let mut prev = self.node_at(position - 1).unwrap();
+        let node = unsafe { unbox_in(prev.as_mut().next.take().unwrap(), &self.alloc) };
+        unsafe { prev.as_mut().next = node.next };
+        self.len -= 1;
+        Ok(node.value)
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == value)
+    }
+
+    /// Reverses the list in place by flipping every `next` pointer.
+    pub fn reverse(&mut self) {
+        let mut reversed = None;
+        let mut current = self.head.take();
+
+        while let Some(mut node) = current {
+            unsafe {
+                current = node.as_ref().next;
+                node.as_mut().next = reversed;
+            }
+            reversed = Some(node);
+        }
+
+        self.head = reversed;
+    }
+
+    /// Moves every element of `other` onto the end of this list. The nodes
+    /// are relinked when the two allocators are interchangeable, otherwise
+    /// the values are moved into nodes from this list's allocator.
+    pub fn append(&mut self, other: &mut LinkedList<T, A>) {
+        if !self.alloc.is_interchangeable(&other.alloc) {
+            self.extend(std::iter::from_fn(|| other.pop_front()));
+            return;
+        }
+
+        let Some(first) = other.head.take() else {
+            return;
+        };
+
+        match self.last_node() {
+            Some(mut last) => unsafe { last.as_mut().next = Some(first) },
+            None => self.head = Some(first),
+        }
+        self.len += std::mem::take(&mut other.len);
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        // Points at whichever `next` field, or the head, leads to the node
+        // being looked at, so a rejected node can be unlinked in place
+        let mut link: *mut Link<T> = &mut self.head;
+
+        unsafe {
+            while let Some(node) = *link {
+                if f(&node.as_ref().value) {
+                    link = &mut (*node.as_ptr()).next;
+                } else {
+                    let node = unbox_in(node, &self.alloc);
+                    *link = node.next;
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    /// Stable merge sort that relinks the nodes, so no value is moved or
+    /// copied and the list needs no extra memory besides the recursion.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        self.head = unsafe { merge_sort(self.head.take(), self.len, &mut compare) };
+    }
+
+    fn last_node(&self) -> Link<T> {
+        let mut last = self.head?;
+        while let Some(next) = unsafe { last.as_ref().next } {
+            last = next;
+        }
+        Some(last)
+    }
+}
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T, A> {
+        if at > self.len {
+            panic!("Split index ({}) is out of bounds len ({})", at, self.len);
+        }
+
+        let mut other = LinkedList::new_in(self.alloc.clone());
+        if at == 0 {
+            std::mem::swap(self, &mut other);
+            return other;
+        }
+
+        let mut last = self.head.unwrap();
+        for _ in 1..at {
+            last = unsafe { last.as_ref().next.unwrap() };
+        }
+
+        other.head = unsafe { last.as_mut().next.take() };
+        other.len = self.len - at;
+        self.len = at;
+        other
+    }
+}
+
+// Nodes are freed one at a time so long lists can't overflow the stack
+impl<T, A: Allocator> Drop for LinkedList<T, A> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+// The list owns its nodes exclusively, just like a chain of boxes would
+unsafe impl<T: Send, A: Allocator + Send> Send for LinkedList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for LinkedList<T, A> {}
+
+// Sorts the `len` nodes starting at `head` and returns the new first node
+unsafe fn merge_sort<T, F>(head: Link<T>, len: usize, compare: &mut F) -> Link<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if len <= 1 {
+        return head;
+    }
+
+    let mut middle = head.unwrap();
+    for _ in 1..len / 2 {
+        middle = middle.as_ref().next.unwrap();
+    }
+    let right = middle.as_mut().next.take();
+
+    let left = merge_sort(head, len / 2, compare);
+    let right = merge_sort(right, len - len / 2, compare);
+    merge(left, right, compare)
+}
+
+// Merges two sorted runs, taking from the left one on ties to stay stable
+unsafe fn merge<T, F>(mut left: Link<T>, mut right: Link<T>, compare: &mut F) -> Link<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut head = None;
+    let mut tail: *mut Link<T> = &mut head;
+
+    loop {
+        let node = match (left, right) {
+            (Some(l), Some(r)) => {
+                if compare(&l.as_ref().value, &r.as_ref().value) != Ordering::Greater {
+                    left = l.as_ref().next;
+                    l
+                } else {
+                    right = r.as_ref().next;
+                    r
+                }
+            }
+            (rest, None) | (None, rest) => {
+                *tail = rest;
+                return head;
+            }
+        };
+
+        *tail = Some(node);
+        tail = &mut (*node.as_ptr()).next;
+    }
+}
+
+impl<T, A: Allocator> LinkedList<T, A> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.next = node.next;
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    next: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.next.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.next = node.next;
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    list: LinkedList<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for LinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Find the last node once instead of walking the list for every push
+        let mut last = self.last_node();
+
+        for value in iter {
+            let node = box_in(Node { value, next: None }, &self.alloc);
+            match last {
+                Some(mut last) => unsafe { last.as_mut().next = Some(node) },
+                None => self.head = Some(node),
+            }
+            last = Some(node);
+            self.len += 1;
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for LinkedList<T, A> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.extend(self.iter().cloned());
+        list
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for LinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for LinkedList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for LinkedList<T, A> {}
+
+impl<T: Hash, A: Allocator> Hash for LinkedList<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashing the length first keeps lists of lists from colliding when
+        // their elements are regrouped
+        state.write_usize(self.len);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn push_front() {
+        let mut list = LinkedList::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&3));
+        assert_eq!(list.back(), Some(&1));
+    }
+
+    #[test]
+    fn push_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn pop_front() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn pop_back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn remove() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.remove(1), 2);
+        assert_eq!(list.remove(0), 1);
+        assert_eq!(list.remove(0), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_out_of_bounds() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        list.remove(3);
+    }
+
+    #[test]
+    fn front() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.front(), Some(&1));
+    }
+
+    #[test]
+    fn back() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut list = LinkedList::new();
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn len() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.len(), 0);
+
+        list.push_back(1);
+        assert_eq!(list.len(), 1);
+
+        list.push_back(2);
+        assert_eq!(list.len(), 2);
+
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn counting_allocator() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = LinkedList::new_in(&counter);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+        assert_eq!(counter.live_allocations(), 3);
+
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.remove(1), 1);
+        assert_eq!(counter.live_allocations(), 1);
+
+        drop(list);
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    #[test]
+    fn bump_allocator() {
+        use crate::allocator::BumpAllocator;
+
+        let arena = BumpAllocator::new(1024);
+        let mut list = LinkedList::new_in(&arena);
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.back(), Some(&9));
+        assert!(arena.used() > 0);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_front(), Some(20));
+        assert_eq!(list.pop_front(), Some(30));
+    }
+
+    #[test]
+    fn collect_and_eq() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        let same: LinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let other: LinkedList<i32> = (1..=4).collect();
+
+        assert_eq!(list, same);
+        assert_ne!(list, other);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn for_loops() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+
+        for value in &mut list {
+            *value *= 2;
+        }
+
+        let mut seen = Vec::new();
+        for value in &list {
+            seen.push(*value);
+        }
+        assert_eq!(seen, vec![2, 4, 6]);
+
+        let mut owned = list.into_iter();
+        assert_eq!(owned.len(), 3);
+        assert_eq!(owned.next(), Some(2));
+        assert_eq!(owned.collect::<Vec<_>>(), vec![4, 6]);
+    }
+
+    #[test]
+    fn extend() {
+        let mut list = LinkedList::new();
+        list.extend(vec![1, 2]);
+        list.extend(&[3, 4]);
+        list.push_back(5);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.back(), Some(&5));
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let list: LinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut copy = list.clone();
+        copy.push_back("c".to_string());
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(copy.len(), 3);
+        assert_eq!(copy.iter().take(2).collect::<Vec<_>>(), list.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn debug() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", LinkedList::<i32>::new()), "[]");
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: LinkedList<i32> = (1..=3).collect();
+        let b: LinkedList<i32> = (1..=3).collect();
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn into_iter_drops_the_rest() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = LinkedList::new_in(&counter);
+        list.extend(0..10);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    fn to_vec<T: Clone>(list: &LinkedList<T>) -> Vec<T> {
+        list.iter().cloned().collect()
+    }
+
+    #[test]
+    fn drop_millions_of_nodes() {
+        let count = crate::collections::stress_count(3_000_000);
+        let mut list = LinkedList::new();
+        for i in 0..count {
+            list.push_front(i);
+        }
+
+        assert_eq!(list.len(), count);
+        drop(list);
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<LinkedList<i32>>();
+
+        let list: LinkedList<i32> = (0..10).collect();
+        let sum = std::thread::spawn(move || list.iter().sum::<i32>()).join().unwrap();
+        assert_eq!(sum, 45);
+    }
+
+    #[test]
+    fn reverse() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        list.reverse();
+        assert_eq!(to_vec(&list), vec![5, 4, 3, 2, 1]);
+        assert_eq!(list.back(), Some(&1));
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn append() {
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let mut other: LinkedList<i32> = (4..=6).collect();
+
+        list.append(&mut other);
+        assert_eq!(to_vec(&list), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 6);
+        assert!(other.is_empty());
+
+        let mut empty = LinkedList::new();
+        empty.append(&mut list);
+        assert_eq!(empty.len(), 6);
+        list.append(&mut other);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn append_from_other_arena() {
+        use crate::allocator::BumpAllocator;
+
+        let mut list = LinkedList::new_in(BumpAllocator::new(4096));
+        let mut other = LinkedList::new_in(BumpAllocator::new(4096));
+        list.extend(1..=3);
+        other.extend(4..=6);
+
+        // The source's arena is freed here, so its nodes must not be reused
+        list.append(&mut other);
+        drop(other);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 6);
+    }
+
+    #[test]
+    fn append_within_shared_arena_relinks() {
+        use crate::allocator::BumpAllocator;
+
+        let arena = BumpAllocator::new(4096);
+        let mut list = LinkedList::new_in(&arena);
+        let mut other = LinkedList::new_in(&arena);
+        list.extend(1..=3);
+        other.extend(4..=6);
+
+        let used = arena.used();
+        list.append(&mut other);
+        assert_eq!(arena.used(), used);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: LinkedList<i32> = (0..6).collect();
+
+        let back = list.split_off(4);
+        assert_eq!(to_vec(&list), vec![0, 1, 2, 3]);
+        assert_eq!(to_vec(&back), vec![4, 5]);
+        assert_eq!(back.len(), 2);
+
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(all.len(), 4);
+
+        let mut all = all;
+        assert!(all.split_off(4).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Split index (4) is out of bounds len (3)")]
+    fn split_off_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.split_off(4);
+    }
+
+    #[test]
+    fn contains() {
+        let list: LinkedList<i32> = (0..3).collect();
+        assert!(list.contains(&2));
+        assert!(!list.contains(&3));
+    }
+
+    #[test]
+    fn retain() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = LinkedList::new_in(&counter);
+        list.extend(0..10);
+
+        list.retain(|value| value % 3 != 0);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4, 5, 7, 8]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(counter.live_allocations(), 6);
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    #[test]
+    fn sort() {
+        let mut list: LinkedList<i32> = vec![5, 3, 9, 1, 3, 7, 0, 8].into_iter().collect();
+        list.sort();
+        assert_eq!(to_vec(&list), vec![0, 1, 3, 3, 5, 7, 8, 9]);
+        assert_eq!(list.len(), 8);
+
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        empty.sort();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn sort_by_is_stable() {
+        let pairs = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e'), (2, 'f')];
+        let mut list: LinkedList<(i32, char)> = pairs.iter().copied().collect();
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut expected = pairs;
+        expected.sort_by_key(|pair| pair.0);
+        assert_eq!(to_vec(&list), expected);
+    }
+
+    #[test]
+    fn sort_relinks_nodes() {
+        let mut list: LinkedList<i32> = (0..1000).rev().collect();
+        let addresses: Vec<*const i32> = list.iter().map(|value| value as *const i32).collect();
+        list.sort();
+
+        assert_eq!(to_vec(&list), (0..1000).collect::<Vec<_>>());
+        // Each value still lives in the node it was pushed into
+        let sorted: Vec<*const i32> = list.iter().map(|value| value as *const i32).collect();
+        assert_eq!(sorted, addresses.into_iter().rev().collect::<Vec<_>>());
+        list.push_back(1000);
+        assert_eq!(list.back(), Some(&1000));
+    }
+
+    #[test]
+    fn get_and_get_mut() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.get(5), None);
+
+        *list.get_mut(2).unwrap() = 20;
+        *list.get_mut(4).unwrap() = 40;
+        assert_eq!(list.get_mut(5), None);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 20, 3, 40]);
+    }
+
+    #[test]
+    fn insert() {
+        let mut list = LinkedList::new();
+        list.insert(0, 2);
+        list.insert(0, 0);
+        list.insert(1, 1);
+        list.insert(3, 4);
+        list.insert(3, 3);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(list.back(), Some(&4));
+        list.push_back(5);
+        assert_eq!(list.get(5), Some(&5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Insertion index (4) is out of bounds len (3)")]
+    fn insert_out_of_bounds() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.insert(4, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Removal index (3) is out of bounds len (3)")]
+    fn remove_out_of_bounds_message() {
+        let mut list: LinkedList<i32> = (0..3).collect();
+        list.remove(3);
+    }
+
+    #[test]
+    fn try_insert_and_try_remove() {
+        let mut list: LinkedList<String> = ["a", "c"].iter().map(|s| s.to_string()).collect();
+
+        assert_eq!(list.try_insert(1, "b".to_string()), Ok(()));
+        let error = list.try_insert(4, "x".to_string()).unwrap_err();
+        assert_eq!(error.error, IndexOutOfBounds { index: 4, len: 3 });
+        assert_eq!(error.to_string(), "insertion index (4) is out of bounds len (3)");
+        assert_eq!(error.into_value(), "x");
+
+        assert_eq!(list.try_remove(3), Err(IndexOutOfBounds { index: 3, len: 3 }));
+        assert_eq!(list.try_remove(2), Ok("c".to_string()));
+        assert_eq!(list.try_remove(0), Ok("a".to_string()));
+        assert_eq!(list.try_remove(0), Ok("b".to_string()));
+        assert_eq!(list.try_remove(0), Err(IndexOutOfBounds { index: 0, len: 0 }));
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+/// The operations every hash table in this crate supports, whatever
+/// collision strategy it uses, so they can be swapped and compared.
+pub trait Map<K, V> {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    fn exists<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    /// Number of slots, or chain nodes, a lookup of `key` inspects before
+    /// reaching it, or `None` if the key isn't stored.
+    fn probe_length<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Map;
+    use std::collections::HashMap;
+
+    // Inserts, overwrites and removes keys on fresh maps from `new`
+    pub(crate) fn check_map_basics<M: Map<String, i32>>(new: impl Fn() -> M) {
+        let mut map = new();
+        for i in 0..1000 {
+            assert_eq!(map.insert(format!("key{}", i), i), None);
+        }
+
+        assert_eq!(map.len(), 1000);
+        for i in 0..1000 {
+            let key = format!("key{}", i);
+            assert_eq!(map.get(&key), Some(&i));
+        }
+        assert_eq!(map.get("key1000"), None);
+
+        let mut map = new();
+        map.insert("a".to_string(), 1);
+        assert_eq!(map.insert("a".to_string(), 2), Some(1));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get("a"), Some(&2));
+
+        let mut map = new();
+        for i in 0..100 {
+            map.insert(format!("key{}", i), i);
+        }
+
+        for i in (0..100).step_by(2) {
+            let key = format!("key{}", i);
+            assert_eq!(map.remove(&key), Some(i));
+            assert!(!map.exists(&key));
+        }
+
+        assert_eq!(map.len(), 50);
+        for i in (1..100).step_by(2) {
+            let key = format!("key{}", i);
+            assert_eq!(map.get(&key), Some(&i));
+        }
+    }
+
+    // Runs a random mix of operations against both `map` and std's HashMap
+    // and checks they always agree
+    pub(crate) fn check_map<M: Map<u64, u64>>(mut map: M) {
+        // xorshift64, so the test is reproducible without extra dependencies
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut expected: HashMap<u64, u64> = HashMap::new();
+
+        for step in 0..50_000 {
+            let key = next() % 1024;
+            match next() % 5 {
+                0 | 1 => {
+                    let value = next();
+                    assert_eq!(map.insert(key, value), expected.insert(key, value), "step {}", step);
+                }
+                2 => assert_eq!(map.remove(&key), expected.remove(&key), "step {}", step),
+                3 => {
+                    if let Some(value) = map.get_mut(&key) {
+                        *value += 1;
+                    }
+                    if let Some(value) = expected.get_mut(&key) {
+                        *value += 1;
+                    }
+                }
+                _ => {
+                    assert_eq!(map.get(&key), expected.get(&key), "step {}", step);
+                    assert_eq!(map.exists(&key), expected.contains_key(&key), "step {}", step);
+                    assert_eq!(map.probe_length(&key).is_some(), expected.contains_key(&key), "step {}", step);
+                }
+            }
+
+            assert_eq!(map.len(), expected.len(), "step {}", step);
+        }
+
+        for (key, value) in &expected {
+            assert_eq!(map.get(key), Some(value));
+            assert!(map.probe_length(key).unwrap() >= 1);
+        }
+        assert_eq!(map.is_empty(), expected.is_empty());
+    }
+}
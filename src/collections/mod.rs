@@ -0,0 +1,29 @@
+pub mod error;
+mod raw_buffer;
+pub mod vector;
+pub mod linked_list;
+pub mod persistent_linked_list;
+pub mod tail_linked_list;
+pub mod doubly_linked_list;
+pub mod deque;
+pub mod queue_tail_linked_list;
+pub mod queue_fixed_array;
+pub mod queue_spsc;
+pub mod queue_mpmc;
+pub mod map;
+pub mod hash_table_linear_probing;
+pub mod hash_table_open_addressing;
+pub mod hash_table_quadratic_probing;
+pub mod hash_table_double_hashing;
+pub mod hash_table_robin_hood;
+pub mod hash_table_separate_chaining;
+pub mod hash_table_swiss;
+pub mod hash_set;
+pub mod index_map;
+
+// Number of elements for the long-running tests. Miri is far slower, so it
+// runs them with a thousandth of the elements.
+#[cfg(test)]
+pub(crate) const fn stress_count(count: usize) -> usize {
+    if cfg!(miri) { count / 1_000 } else { count }
+}
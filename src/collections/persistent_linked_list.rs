@@ -0,0 +1,301 @@
+// The `Rc` and `Arc` flavours only differ in the pointer they share nodes
+// through, so both are generated from one definition
+macro_rules! persistent_list {
+    ($pointer:ident) => {
+        use std::fmt;
+        use std::iter::FromIterator;
+
+        type Link<T> = Option<$pointer<Node<T>>>;
+
+        struct Node<T> {
+            value: T,
+            next: Link<T>,
+        }
+
+        /// An immutable singly linked list. `prepend` and `tail` return new
+        /// lists that share their nodes with the original instead of copying
+        /// them, so old versions stay valid and cost nothing to keep.
+        pub struct List<T> {
+            head: Link<T>,
+            len: usize,
+        }
+
+        impl<T> List<T> {
+            pub fn new() -> Self {
+                Self { head: None, len: 0 }
+            }
+
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.head.is_none()
+            }
+
+            /// A new list with `value` in front of this one.
+            pub fn prepend(&self, value: T) -> List<T> {
+                List {
+                    head: Some($pointer::new(Node {
+                        value,
+                        next: self.head.clone(),
+                    })),
+                    len: self.len + 1,
+                }
+            }
+
+            pub fn head(&self) -> Option<&T> {
+                self.head.as_ref().map(|node| &node.value)
+            }
+
+            /// The list without its first element, or an empty list.
+            pub fn tail(&self) -> List<T> {
+                match &self.head {
+                    Some(node) => List {
+                        head: node.next.clone(),
+                        len: self.len - 1,
+                    },
+                    None => List::new(),
+                }
+            }
+
+            pub fn iter(&self) -> Iter<'_, T> {
+                Iter {
+                    next: self.head.as_deref(),
+                    len: self.len,
+                }
+            }
+        }
+
+        pub struct Iter<'a, T> {
+            next: Option<&'a Node<T>>,
+            len: usize,
+        }
+
+        impl<'a, T> Iterator for Iter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<&'a T> {
+                self.next.map(|node| {
+                    self.next = node.next.as_deref();
+                    self.len -= 1;
+                    &node.value
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+        impl<'a, T> IntoIterator for &'a List<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Iter<'a, T> {
+                self.iter()
+            }
+        }
+
+        // Frees nodes one at a time so long lists can't overflow the stack,
+        // and stops at the first node another list still shares. Unlike
+        // `try_unwrap`, `into_inner` always hands the node to the last owner,
+        // even when two threads drop their references at the same time.
+        impl<T> Drop for List<T> {
+            fn drop(&mut self) {
+                let mut link = self.head.take();
+                while let Some(node) = link {
+                    match $pointer::into_inner(node) {
+                        Some(mut node) => link = node.next.take(),
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        // Cloning only bumps the reference count of the first node
+        impl<T> Clone for List<T> {
+            fn clone(&self) -> Self {
+                List {
+                    head: self.head.clone(),
+                    len: self.len,
+                }
+            }
+        }
+
+        // Keeps the iterator's order, so `collect()` of `[1, 2, 3]` has 1 as
+        // its head
+        impl<T> FromIterator<T> for List<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let values: Vec<T> = iter.into_iter().collect();
+                let mut list = List::new();
+                for value in values.into_iter().rev() {
+                    list = list.prepend(value);
+                }
+                list
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for List<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for List<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.len == other.len && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: Eq> Eq for List<T> {}
+
+        impl<T> Default for List<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+/// A persistent list sharing its nodes through `Rc`, for single threaded use.
+pub mod rc {
+    use std::rc::Rc;
+
+    persistent_list!(Rc);
+}
+
+/// A persistent list sharing its nodes through `Arc`, so versions of it can
+/// be sent to and shared between threads.
+pub mod arc {
+    use std::sync::Arc;
+
+    persistent_list!(Arc);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{arc, rc};
+
+    #[test]
+    fn prepend_and_tail() {
+        let empty = rc::List::new();
+        let one = empty.prepend(1);
+        let two = one.prepend(2);
+        let three = two.prepend(3);
+
+        assert_eq!(three.head(), Some(&3));
+        assert_eq!(three.len(), 3);
+        assert_eq!(three.tail(), two);
+        assert_eq!(three.tail().tail(), one);
+        assert_eq!(one.tail().head(), None);
+        assert!(empty.tail().is_empty());
+    }
+
+    #[test]
+    fn old_versions_stay_intact() {
+        let base: rc::List<i32> = (1..=3).collect();
+        let left = base.prepend(10);
+        let right = base.prepend(20);
+
+        assert_eq!(base.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(left.iter().copied().collect::<Vec<_>>(), vec![10, 1, 2, 3]);
+        assert_eq!(right.iter().copied().collect::<Vec<_>>(), vec![20, 1, 2, 3]);
+        assert_eq!(format!("{:?}", left), "[10, 1, 2, 3]");
+    }
+
+    #[test]
+    fn tails_share_nodes() {
+        let list: rc::List<i32> = (1..=3).collect();
+        let tail = list.tail();
+
+        // Same node in memory, not a copy of it
+        assert!(std::ptr::eq(list.iter().nth(1).unwrap(), tail.head().unwrap()));
+    }
+
+    #[test]
+    fn drop_keeps_shared_nodes_alive() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let shared = rc::List::new().prepend(Rc::clone(&value)).prepend(Rc::clone(&value));
+        let longer = shared.prepend(Rc::clone(&value));
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        drop(shared);
+        assert_eq!(Rc::strong_count(&value), 4);
+        assert_eq!(longer.len(), 3);
+
+        drop(longer);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn long_list_drop() {
+        let count = crate::collections::stress_count(1_000_000);
+
+        let mut list = rc::List::new();
+        for i in 0..count {
+            list = list.prepend(i);
+        }
+        let shared = list.tail();
+        drop(list);
+        assert_eq!(shared.len(), count - 1);
+
+        let mut list = arc::List::new();
+        for i in 0..count {
+            list = list.prepend(i);
+        }
+    }
+
+    #[test]
+    fn arc_concurrent_drop_of_shared_nodes() {
+        use std::sync::Barrier;
+
+        let count = crate::collections::stress_count(1_000_000);
+        let barrier = Barrier::new(2);
+
+        for _ in 0..4 {
+            let mut shared = arc::List::new();
+            for i in 0..count {
+                shared = shared.prepend(i);
+            }
+            let left = shared.prepend(count);
+            let right = shared.prepend(count + 1);
+            drop(shared);
+
+            // Whichever thread drops the shared nodes last must free them
+            // one at a time, not recursively
+            std::thread::scope(|scope| {
+                for list in [left, right] {
+                    let barrier = &barrier;
+                    scope.spawn(move || {
+                        barrier.wait();
+                        drop(list);
+                    });
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn arc_versions_shared_between_threads() {
+        let base: arc::List<i32> = (1..=100).collect();
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let base = base.clone();
+                std::thread::spawn(move || {
+                    let own = base.prepend(i);
+                    own.iter().sum::<i32>()
+                })
+            })
+            .collect();
+
+        let sums: Vec<i32> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+        assert_eq!(sums, vec![5050, 5051, 5052, 5053]);
+        assert_eq!(base.len(), 100);
+    }
+}
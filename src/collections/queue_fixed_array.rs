@@ -0,0 +1,593 @@
+use std::mem::MaybeUninit;
+
+/// What `Queue::enqueue` does when the queue is already full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Panic, leaving the queue as it was.
+    #[default]
+    Reject,
+    /// Evict the oldest element to make room, turning the queue into a ring
+    /// log of the latest `N` elements.
+    OverwriteOldest,
+    /// Discard the element being enqueued.
+    DropNewest,
+}
+
+/// A queue holding up to `N` elements inline, in a ring over a fixed array.
+/// Slots outside the live range are left uninitialised, so any `T` works and
+/// no slot pays for an `Option` discriminant.
+pub struct Queue<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    front: usize,
+    rear: usize,
+    size: usize,
+    policy: OverflowPolicy,
+    // Elements discarded by the overflow policy so far
+    dropped: usize,
+}
+
+impl<T, const N: usize> Queue<T, N> {
+    pub const fn new() -> Self {
+        Self::with_policy(OverflowPolicy::Reject)
+    }
+
+    pub const fn with_policy(policy: OverflowPolicy) -> Self {
+        Self {
+            data: [const { MaybeUninit::uninit() }; N],
+            front: 0,
+            rear: 0,
+            size: 0,
+            policy,
+            dropped: 0,
+        }
+    }
+
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+    }
+
+    /// How many elements the overflow policy has evicted or discarded.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.size == N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Adds `value` at the back. A full queue handles it according to its
+    /// `OverflowPolicy`.
+    pub fn enqueue(&mut self, value: T) {
+        if self.is_full() {
+            match self.policy {
+                OverflowPolicy::Reject => panic!("Queue is full"),
+                OverflowPolicy::OverwriteOldest => {
+                    self.dropped += 1;
+                    if self.pop().is_none() {
+                        // A zero-capacity queue has nothing to evict
+                        return;
+                    }
+                }
+                OverflowPolicy::DropNewest => {
+                    self.dropped += 1;
+                    return;
+                }
+            }
+        }
+
+        self.push(value);
+    }
+
+    /// Adds `value` at the back, or hands it back if the queue is full,
+    /// whatever the overflow policy.
+    pub fn try_enqueue(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.push(value);
+        Ok(())
+    }
+
+    // Writes into the slot after the last element, which must be free
+    fn push(&mut self, value: T) {
+        self.data[self.rear].write(value);
+        self.rear += 1;
+        self.size += 1;
+
+        if self.rear == N {
+            self.rear = 0;
+        }
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            panic!("Queue is empty");
+        }
+
+        self.pop()
+    }
+
+    pub fn try_dequeue(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        // The slot is left uninitialised again, so it is read out exactly once
+        let value = unsafe { self.data[self.front].assume_init_read() };
+        self.front += 1;
+        self.size -= 1;
+
+        if self.front == N {
+            self.front = 0;
+        }
+
+        Some(value)
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { self.data[self.front].assume_init_ref() })
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { self.data[self.front].assume_init_mut() })
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+        self.front = 0;
+        self.rear = 0;
+    }
+
+    // The live slots in order, split where the ring wraps around
+    fn live_ranges(&self) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        if self.front + self.size <= N {
+            (self.front..self.front + self.size, 0..0)
+        } else {
+            (self.front..N, 0..self.front + self.size - N)
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.live_ranges();
+
+        // Every slot in the live ranges is initialised
+        unsafe {
+            Iter {
+                front: slice_assume_init(&self.data[front]).iter(),
+                back: slice_assume_init(&self.data[back]).iter(),
+            }
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.live_ranges();
+        let (wrapped, tail) = self.data.split_at_mut(front.start);
+
+        unsafe {
+            IterMut {
+                front: slice_assume_init_mut(&mut tail[..front.len()]).iter_mut(),
+                back: slice_assume_init_mut(&mut wrapped[back]).iter_mut(),
+            }
+        }
+    }
+}
+
+unsafe fn slice_assume_init<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+    &*(slice as *const [MaybeUninit<T>] as *const [T])
+}
+
+unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    &mut *(slice as *mut [MaybeUninit<T>] as *mut [T])
+}
+
+impl<T, const N: usize> Drop for Queue<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+pub struct Iter<'a, T> {
+    front: std::slice::Iter<'a, T>,
+    back: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    front: std::slice::IterMut<'a, T>,
+    back: std::slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T, const N: usize> {
+    queue: Queue<T, N>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.size, Some(self.queue.size))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> IntoIterator for Queue<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { queue: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Queue<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut Queue<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: std::fmt::Debug, const N: usize> std::fmt::Debug for Queue<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_new() {
+        let queue = super::Queue::<i32, 3>::new();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_enqueue() {
+        let mut queue = super::Queue::<i32, 3>::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert!(!queue.is_empty());
+    }
+
+    #[test]
+    fn test_dequeue() {
+        let mut queue = super::Queue::<i32, 3>::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_enqueue_panic() {
+        let mut queue = super::Queue::<i32, 3>::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        queue.enqueue(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dequeue_panic() {
+        let mut queue = super::Queue::<i32, 3>::new();
+        queue.dequeue();
+    }
+
+    #[test]
+    fn test_enqueue_dequeue() {
+        let mut queue = super::Queue::<i32, 3>::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+    }
+
+    #[test]
+    fn test_non_copy_values() {
+        let mut queue = super::Queue::<String, 2>::new();
+        queue.enqueue("a".to_string());
+        queue.enqueue("b".to_string());
+
+        assert_eq!(queue.dequeue(), Some("a".to_string()));
+        queue.enqueue("c".to_string());
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.capacity(), 2);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut queue = super::Queue::<i32, 3>::new();
+        assert_eq!(queue.peek(), None);
+        assert_eq!(queue.peek_mut(), None);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        *queue.peek_mut().unwrap() = 10;
+
+        assert_eq!(queue.peek(), Some(&10));
+        assert_eq!(queue.dequeue(), Some(10));
+        assert_eq!(queue.peek(), Some(&2));
+    }
+
+    #[test]
+    fn test_drops_remaining_elements() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut queue = super::Queue::<Rc<()>, 4>::new();
+        for _ in 0..4 {
+            queue.enqueue(Rc::clone(&value));
+        }
+        queue.dequeue();
+        queue.enqueue(Rc::clone(&value));
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        drop(queue);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_clear() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut queue = super::Queue::<Rc<()>, 3>::new();
+        queue.enqueue(Rc::clone(&value));
+        queue.enqueue(Rc::clone(&value));
+
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        for _ in 0..3 {
+            queue.enqueue(Rc::clone(&value));
+        }
+        assert!(queue.is_full());
+    }
+
+    #[test]
+    fn test_iterators_follow_the_ring() {
+        let mut queue = super::Queue::<i32, 4>::new();
+        for i in 0..4 {
+            queue.enqueue(i);
+        }
+        queue.dequeue();
+        queue.dequeue();
+        queue.enqueue(4);
+
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(queue.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2]);
+        assert_eq!(queue.iter().len(), 3);
+
+        for value in &mut queue {
+            *value *= 10;
+        }
+        assert_eq!(format!("{:?}", queue), "[20, 30, 40]");
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec![20, 30, 40]);
+    }
+
+    #[test]
+    fn test_into_iter_drops_the_rest() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut queue = super::Queue::<Rc<()>, 3>::new();
+        for _ in 0..3 {
+            queue.enqueue(Rc::clone(&value));
+        }
+
+        let mut iter = queue.into_iter();
+        iter.next();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_in_static() {
+        use std::sync::Mutex;
+
+        static QUEUE: Mutex<super::Queue<u32, 4>> = Mutex::new(super::Queue::new());
+
+        QUEUE.lock().unwrap().enqueue(7);
+        assert_eq!(QUEUE.lock().unwrap().dequeue(), Some(7));
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let queue = super::Queue::<i32, 0>::new();
+        assert!(queue.is_empty());
+        assert!(queue.is_full());
+        assert_eq!(queue.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_try_enqueue_and_try_dequeue() {
+        let mut queue = super::Queue::<String, 2>::new();
+        assert_eq!(queue.try_dequeue(), None);
+
+        assert_eq!(queue.try_enqueue("a".to_string()), Ok(()));
+        assert_eq!(queue.try_enqueue("b".to_string()), Ok(()));
+        assert_eq!(queue.try_enqueue("c".to_string()), Err("c".to_string()));
+
+        assert_eq!(queue.try_dequeue(), Some("a".to_string()));
+        assert_eq!(queue.try_enqueue("c".to_string()), Ok(()));
+        assert_eq!(queue.iter().cloned().collect::<Vec<_>>(), vec!["b", "c"]);
+        assert_eq!(queue.dropped(), 0);
+    }
+
+    #[test]
+    fn test_try_enqueue_ignores_policy() {
+        let mut queue = super::Queue::<i32, 1>::with_policy(super::OverflowPolicy::OverwriteOldest);
+        queue.enqueue(1);
+
+        assert_eq!(queue.try_enqueue(2), Err(2));
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.dropped(), 0);
+    }
+
+    #[test]
+    fn test_overwrite_oldest() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut queue = super::Queue::<(i32, Rc<()>), 3>::with_policy(super::OverflowPolicy::OverwriteOldest);
+        for i in 0..7 {
+            queue.enqueue((i, Rc::clone(&value)));
+        }
+
+        assert_eq!(queue.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(queue.dropped(), 4);
+        // Evicted elements are dropped, not leaked
+        assert_eq!(Rc::strong_count(&value), 4);
+    }
+
+    #[test]
+    fn test_drop_newest() {
+        let mut queue = super::Queue::<i32, 3>::with_policy(super::OverflowPolicy::DropNewest);
+        for i in 0..5 {
+            queue.enqueue(i);
+        }
+
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(queue.dropped(), 2);
+
+        queue.dequeue();
+        queue.enqueue(5);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![1, 2, 5]);
+        assert_eq!(queue.dropped(), 2);
+    }
+
+    #[test]
+    fn test_set_policy() {
+        let mut queue = super::Queue::<i32, 2>::new();
+        assert_eq!(queue.policy(), super::OverflowPolicy::Reject);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.set_policy(super::OverflowPolicy::OverwriteOldest);
+        queue.enqueue(3);
+
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(queue.dropped(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Queue is full")]
+    fn test_reject_policy_panics() {
+        let mut queue = super::Queue::<i32, 1>::with_policy(super::OverflowPolicy::Reject);
+        queue.enqueue(1);
+        queue.enqueue(2);
+    }
+
+    #[test]
+    fn test_zero_capacity_policies() {
+        let mut overwrite = super::Queue::<i32, 0>::with_policy(super::OverflowPolicy::OverwriteOldest);
+        overwrite.enqueue(1);
+        assert!(overwrite.is_empty());
+        assert_eq!(overwrite.dropped(), 1);
+
+        let mut drop_newest = super::Queue::<i32, 0>::with_policy(super::OverflowPolicy::DropNewest);
+        drop_newest.enqueue(1);
+        assert_eq!(drop_newest.dropped(), 1);
+    }
+}
@@ -0,0 +1,296 @@
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::sync::epoch::{self, Guard};
+use crate::sync::CachePadded;
+
+struct Node<T> {
+    // Uninitialised in the dummy node at the head, and once dequeued
+    value: MaybeUninit<T>,
+    next: AtomicPtr<Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn boxed(value: MaybeUninit<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node {
+            value,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }))
+    }
+}
+
+/// A lock-free multi-producer, multi-consumer queue, following Michael and
+/// Scott's linked list algorithm. `head` always points at a dummy node whose
+/// successor holds the front element, so producers only touch the tail and
+/// consumers only the head. A thread that finds `tail` lagging behind the
+/// last node helps move it on rather than waiting.
+///
+/// Dequeued dummy nodes may still be read by other threads, so they are
+/// freed through `sync::epoch` once no thread can reach them.
+pub struct Queue<T> {
+    head: CachePadded<AtomicPtr<Node<T>>>,
+    tail: CachePadded<AtomicPtr<Node<T>>>,
+}
+
+// Values are moved in on one thread and out on another, so they must be
+// `Send`; the queue never hands out shared references to them
+unsafe impl<T: Send> Send for Queue<T> {}
+unsafe impl<T: Send> Sync for Queue<T> {}
+
+impl<T> Queue<T> {
+    pub fn new() -> Self {
+        let dummy = Node::boxed(MaybeUninit::uninit());
+
+        Self {
+            head: CachePadded(AtomicPtr::new(dummy)),
+            tail: CachePadded(AtomicPtr::new(dummy)),
+        }
+    }
+
+    pub fn enqueue(&self, value: T) {
+        let node = Node::boxed(MaybeUninit::new(value));
+        let _guard = epoch::pin();
+
+        loop {
+            let tail = self.tail.load(Ordering::Acquire);
+            let next = unsafe { (*tail).next.load(Ordering::Acquire) };
+
+            if !next.is_null() {
+                // Another enqueue linked its node but hasn't moved the tail yet
+                let _ = self.tail.compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+                continue;
+            }
+
+            let linked = unsafe {
+                (*tail)
+                    .next
+                    .compare_exchange(ptr::null_mut(), node, Ordering::Release, Ordering::Relaxed)
+            };
+
+            if linked.is_ok() {
+                // Failing here is fine, some other thread has already helped
+                let _ = self.tail.compare_exchange(tail, node, Ordering::Release, Ordering::Relaxed);
+                return;
+            }
+        }
+    }
+
+    pub fn dequeue(&self) -> Option<T> {
+        let guard = epoch::pin();
+
+        loop {
+            let head = self.head.load(Ordering::Acquire);
+            let next = unsafe { (*head).next.load(Ordering::Acquire) };
+
+            if next.is_null() {
+                return None;
+            }
+
+            // Never let the head pass the tail, or the tail would point at a
+            // node about to be freed
+            let tail = self.tail.load(Ordering::Acquire);
+            if head == tail {
+                let _ = self.tail.compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed);
+                continue;
+            }
+
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                // Winning the exchange makes `next` the new dummy and gives
+                // this thread sole ownership of its value
+                let value = unsafe { (*next).value.assume_init_read() };
+                unsafe { retire(&guard, head) };
+                return Some(value);
+            }
+        }
+    }
+
+    /// Whether the queue was empty at some point during the call.
+    pub fn is_empty(&self) -> bool {
+        let _guard = epoch::pin();
+        let head = self.head.load(Ordering::Acquire);
+        unsafe { (*head).next.load(Ordering::Acquire).is_null() }
+    }
+}
+
+// Hands the old dummy node to the epoch collector. Its value has already
+// been moved out or was never there, so freeing it drops nothing.
+unsafe fn retire<T>(guard: &Guard, node: *mut Node<T>) {
+    guard.defer_destroy(node);
+}
+
+impl<T> Drop for Queue<T> {
+    fn drop(&mut self) {
+        // No other thread can reach the queue any more, so the nodes are
+        // freed right away
+        let dummy = *self.head.0.get_mut();
+        let mut current = unsafe { Box::from_raw(dummy) }.next.into_inner();
+
+        while !current.is_null() {
+            let mut node = unsafe { Box::from_raw(current) };
+            unsafe { node.value.assume_init_drop() };
+            current = node.next.into_inner();
+        }
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+    use std::thread;
+
+    const PER_THREAD: usize = crate::collections::stress_count(50_000);
+    const THREADS: usize = 4;
+
+    #[test]
+    fn fifo_order() {
+        let queue = Queue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.dequeue(), None);
+
+        for i in 0..10 {
+            queue.enqueue(i);
+        }
+        assert!(!queue.is_empty());
+        for i in 0..10 {
+            assert_eq!(queue.dequeue(), Some(i));
+        }
+        assert_eq!(queue.dequeue(), None);
+
+        queue.enqueue(10);
+        assert_eq!(queue.dequeue(), Some(10));
+    }
+
+    #[test]
+    fn drops_remaining_values() {
+        let value = Arc::new(());
+        let queue = Queue::new();
+        for _ in 0..5 {
+            queue.enqueue(Arc::clone(&value));
+        }
+        drop(queue.dequeue());
+        assert_eq!(Arc::strong_count(&value), 5);
+
+        drop(queue);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn producers_and_consumers() {
+        let queue = Queue::new();
+        let received = AtomicUsize::new(0);
+        let sum = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for producer in 0..THREADS {
+                let queue = &queue;
+                scope.spawn(move || {
+                    for i in 0..PER_THREAD {
+                        queue.enqueue(producer * PER_THREAD + i);
+                    }
+                });
+            }
+
+            for _ in 0..THREADS {
+                let (queue, received, sum) = (&queue, &received, &sum);
+                scope.spawn(move || {
+                    while received.load(Ordering::SeqCst) < THREADS * PER_THREAD {
+                        match queue.dequeue() {
+                            Some(value) => {
+                                sum.fetch_add(value, Ordering::SeqCst);
+                                received.fetch_add(1, Ordering::SeqCst);
+                            }
+                            None => thread::yield_now(),
+                        }
+                    }
+                });
+            }
+        });
+
+        let total = THREADS * PER_THREAD;
+        assert_eq!(received.into_inner(), total);
+        assert_eq!(sum.into_inner(), total * (total - 1) / 2);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn per_producer_order_is_kept() {
+        let queue = Queue::new();
+
+        thread::scope(|scope| {
+            for producer in 0..THREADS {
+                let queue = &queue;
+                scope.spawn(move || {
+                    for i in 0..PER_THREAD {
+                        queue.enqueue((producer, i));
+                    }
+                });
+            }
+
+            // A single consumer sees each producer's elements in the order
+            // they were enqueued, however they interleave
+            let queue = &queue;
+            scope.spawn(move || {
+                let mut next = [0; THREADS];
+                let mut received = 0;
+                while received < THREADS * PER_THREAD {
+                    match queue.dequeue() {
+                        Some((producer, i)) => {
+                            assert_eq!(i, next[producer]);
+                            next[producer] += 1;
+                            received += 1;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn every_value_dropped_once() {
+        struct Counted(Arc<AtomicUsize>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let drops = Arc::new(AtomicUsize::new(0));
+        let dequeued = AtomicUsize::new(0);
+        let queue = Queue::new();
+        let count = PER_THREAD / 10;
+
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                let (queue, drops, dequeued) = (&queue, &drops, &dequeued);
+                scope.spawn(move || {
+                    for i in 0..count {
+                        queue.enqueue(Counted(Arc::clone(drops)));
+                        if i % 2 == 0 && queue.dequeue().is_some() {
+                            dequeued.fetch_add(1, Ordering::SeqCst);
+                        }
+                    }
+                });
+            }
+        });
+
+        // Dequeued values are dropped straight away, the rest by the queue
+        assert_eq!(drops.load(Ordering::SeqCst), dequeued.into_inner());
+        drop(queue);
+        assert_eq!(drops.load(Ordering::SeqCst), THREADS * count);
+    }
+}
@@ -0,0 +1,452 @@
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::sync::CachePadded;
+
+/// A wait-free ring buffer of up to `N` elements, for exactly one producer
+/// thread and one consumer thread. `split` hands out the two ends; the
+/// producer only ever writes `tail` and the consumer only ever writes `head`,
+/// so neither has to wait for the other.
+///
+/// Both positions count up to `2 * N` before wrapping, which tells a full
+/// ring (`N` apart) from an empty one (equal) without wasting a slot.
+pub struct Queue<T, const N: usize> {
+    buffer: [UnsafeCell<MaybeUninit<T>>; N],
+    // Position of the next element to pop, written only by the consumer. The
+    // two positions sit on separate cache lines so the sides don't contend.
+    head: CachePadded<AtomicUsize>,
+    // Position of the next slot to push into, written only by the producer
+    tail: CachePadded<AtomicUsize>,
+}
+
+// Each slot is only ever accessed by one side at a time: the producer until
+// it publishes the slot through `tail`, then the consumer until it hands it
+// back through `head`. Elements cross threads, so they must be `Send`.
+unsafe impl<T: Send, const N: usize> Sync for Queue<T, N> {}
+
+impl<T, const N: usize> Queue<T, N> {
+    pub const fn new() -> Self {
+        assert!(N > 0, "Queue capacity must be non-zero");
+
+        Self {
+            buffer: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            head: CachePadded(AtomicUsize::new(0)),
+            tail: CachePadded(AtomicUsize::new(0)),
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// A snapshot of the number of elements, which may be stale by the time
+    /// it is read if the other side is active.
+    pub fn len(&self) -> usize {
+        distance(self.head.load(Ordering::Acquire), self.tail.load(Ordering::Acquire), N)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits the queue into its producing and consuming ends. Borrowing the
+    /// queue mutably makes sure there is only ever one of each.
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+
+    fn slot(&self, position: usize) -> *mut MaybeUninit<T> {
+        self.buffer[position % N].get()
+    }
+}
+
+// Number of elements between two positions counting up to `2 * n`
+fn distance(head: usize, tail: usize, n: usize) -> usize {
+    if tail >= head {
+        tail - head
+    } else {
+        tail + 2 * n - head
+    }
+}
+
+fn advance(position: usize, by: usize, n: usize) -> usize {
+    let next = position + by;
+    if next >= 2 * n {
+        next - 2 * n
+    } else {
+        next
+    }
+}
+
+impl<T, const N: usize> Drop for Queue<T, N> {
+    fn drop(&mut self) {
+        let mut head = *self.head.0.get_mut();
+        let tail = *self.tail.0.get_mut();
+
+        while head != tail {
+            unsafe { (*self.slot(head)).assume_init_drop() };
+            head = advance(head, 1, N);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The pushing end of a `Queue`.
+pub struct Producer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Producer<'_, T, N> {
+    // Free slots, and the position of the first of them
+    fn vacant(&self) -> (usize, usize) {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        // Acquire so the consumer's reads of freed slots finish before they
+        // are overwritten
+        let head = self.queue.head.load(Ordering::Acquire);
+        (N - distance(head, tail, N), tail)
+    }
+
+    /// Pushes `value`, or hands it back if the queue is full.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let (vacant, tail) = self.vacant();
+        if vacant == 0 {
+            return Err(value);
+        }
+
+        unsafe { (*self.queue.slot(tail)).write(value) };
+        // Release publishes the written slot to the consumer
+        self.queue.tail.store(advance(tail, 1, N), Ordering::Release);
+        Ok(())
+    }
+
+    /// Pushes as many elements from the front of `values` as fit and returns
+    /// how many that was. They all become visible to the consumer at once.
+    pub fn push_slice(&mut self, values: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        let (vacant, tail) = self.vacant();
+        let count = vacant.min(values.len());
+
+        for (offset, value) in values[..count].iter().enumerate() {
+            unsafe { (*self.queue.slot(advance(tail, offset, N))).write(value.clone()) };
+        }
+
+        self.queue.tail.store(advance(tail, count, N), Ordering::Release);
+        count
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.vacant().0 == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+/// The popping end of a `Queue`.
+pub struct Consumer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Consumer<'_, T, N> {
+    // Filled slots, and the position of the first of them
+    fn available(&self) -> (usize, usize) {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        // Acquire so the producer's writes to published slots are visible
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        (distance(head, tail, N), head)
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let (available, head) = self.available();
+        if available == 0 {
+            return None;
+        }
+
+        let value = unsafe { (*self.queue.slot(head)).assume_init_read() };
+        // Release hands the slot back only after it has been read
+        self.queue.head.store(advance(head, 1, N), Ordering::Release);
+        Some(value)
+    }
+
+    /// Pops up to `out.len()` elements into the front of `out` and returns
+    /// how many that was.
+    pub fn pop_into(&mut self, out: &mut [T]) -> usize {
+        let (available, head) = self.available();
+        let count = available.min(out.len());
+
+        for (offset, target) in out[..count].iter_mut().enumerate() {
+            let value = unsafe { (*self.queue.slot(advance(head, offset, N))).assume_init_read() };
+            // Hand the slot back before the old element of `out` is dropped,
+            // so a panicking destructor can't leave a moved out slot counted
+            // as filled
+            self.queue.head.store(advance(head, offset + 1, N), Ordering::Release);
+            *target = value;
+        }
+
+        count
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        let (available, head) = self.available();
+        if available == 0 {
+            return None;
+        }
+
+        // The producer can't touch the slot until this consumer pops it
+        Some(unsafe { (*self.queue.slot(head)).assume_init_ref() })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.available().0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.available().0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // Waiting sides yield rather than spin, so the tests also finish on a
+    // single core
+    const STRESS_COUNT: usize = crate::collections::stress_count(1_000_000);
+
+    #[test]
+    fn push_and_pop() {
+        let mut queue = Queue::<i32, 4>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        assert_eq!(consumer.pop(), None);
+        for i in 0..4 {
+            assert_eq!(producer.push(i), Ok(()));
+        }
+        assert!(producer.is_full());
+        assert_eq!(producer.push(4), Err(4));
+
+        assert_eq!(consumer.peek(), Some(&0));
+        assert_eq!(consumer.len(), 4);
+        assert_eq!(consumer.pop(), Some(0));
+        assert_eq!(producer.push(4), Ok(()));
+        for i in 1..5 {
+            assert_eq!(consumer.pop(), Some(i));
+        }
+        assert!(consumer.is_empty());
+    }
+
+    #[test]
+    fn wraps_around_many_times() {
+        let mut queue = Queue::<usize, 3>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        for i in 0..100 {
+            producer.push(i).unwrap();
+            producer.push(i + 1000).unwrap();
+            assert_eq!(consumer.pop(), Some(i));
+            assert_eq!(consumer.pop(), Some(i + 1000));
+        }
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    fn push_slice_and_pop_into() {
+        let mut queue = Queue::<i32, 5>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        assert_eq!(producer.push_slice(&[1, 2, 3]), 3);
+        assert_eq!(producer.push_slice(&[4, 5, 6, 7]), 2);
+        assert_eq!(producer.push_slice(&[8]), 0);
+
+        let mut out = [0; 4];
+        assert_eq!(consumer.pop_into(&mut out), 4);
+        assert_eq!(out, [1, 2, 3, 4]);
+
+        // This batch wraps around the end of the buffer
+        assert_eq!(producer.push_slice(&[6, 7, 8]), 3);
+        let mut out = [0; 8];
+        assert_eq!(consumer.pop_into(&mut out), 4);
+        assert_eq!(out[..4], [5, 6, 7, 8]);
+        assert_eq!(consumer.pop_into(&mut out), 0);
+    }
+
+    #[test]
+    fn pop_into_with_panicking_drop() {
+        use std::panic::{self, AssertUnwindSafe};
+        use std::sync::atomic::AtomicUsize;
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct PanicOnDrop(bool);
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+                if self.0 {
+                    panic!("drop panicked");
+                }
+            }
+        }
+
+        let mut queue = Queue::<PanicOnDrop, 4>::new();
+        let mut out = [PanicOnDrop(true), PanicOnDrop(false)];
+        {
+            let (mut producer, mut consumer) = queue.split();
+            producer.push(PanicOnDrop(false)).ok().unwrap();
+            producer.push(PanicOnDrop(false)).ok().unwrap();
+
+            let result = panic::catch_unwind(AssertUnwindSafe(|| consumer.pop_into(&mut out)));
+            assert!(result.is_err());
+        }
+
+        // Every object is dropped exactly once, whether it ended up in `out`
+        // or is still in the queue
+        drop(out);
+        drop(queue);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn drops_remaining_elements() {
+        use std::sync::Arc;
+
+        let value = Arc::new(());
+        let mut queue = Queue::<Arc<()>, 4>::new();
+        {
+            let (mut producer, mut consumer) = queue.split();
+            for _ in 0..4 {
+                producer.push(Arc::clone(&value)).unwrap();
+            }
+            consumer.pop();
+            producer.push(Arc::clone(&value)).unwrap();
+        }
+        assert_eq!(Arc::strong_count(&value), 5);
+
+        drop(queue);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn in_static() {
+        use std::sync::Mutex;
+
+        static QUEUE: Mutex<Queue<u32, 8>> = Mutex::new(Queue::new());
+
+        let mut queue = QUEUE.lock().unwrap();
+        let (mut producer, mut consumer) = queue.split();
+        producer.push(7).unwrap();
+        assert_eq!(consumer.pop(), Some(7));
+    }
+
+    #[test]
+    fn stress_across_threads() {
+        let mut queue = Queue::<usize, 64>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..STRESS_COUNT {
+                    let mut value = i;
+                    while let Err(rejected) = producer.push(value) {
+                        value = rejected;
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            scope.spawn(move || {
+                for expected in 0..STRESS_COUNT {
+                    let value = loop {
+                        match consumer.pop() {
+                            Some(value) => break value,
+                            None => thread::yield_now(),
+                        }
+                    };
+                    assert_eq!(value, expected);
+                }
+            });
+        });
+
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn stress_batches_across_threads() {
+        let mut queue = Queue::<usize, 32>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                let values: Vec<usize> = (0..STRESS_COUNT).collect();
+                let mut sent = 0;
+                while sent < values.len() {
+                    let end = (sent + 7).min(values.len());
+                    match producer.push_slice(&values[sent..end]) {
+                        0 => thread::yield_now(),
+                        pushed => sent += pushed,
+                    }
+                }
+            });
+
+            scope.spawn(move || {
+                let mut out = [0; 5];
+                let mut expected = 0;
+                while expected < STRESS_COUNT {
+                    let count = consumer.pop_into(&mut out);
+                    if count == 0 {
+                        thread::yield_now();
+                    }
+                    for &value in &out[..count] {
+                        assert_eq!(value, expected);
+                        expected += 1;
+                    }
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn stress_owned_values() {
+        // Boxed values catch any slot read twice or never
+        let mut queue = Queue::<Box<usize>, 16>::new();
+        let (mut producer, mut consumer) = queue.split();
+        let count = STRESS_COUNT / 10;
+
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                for i in 0..count {
+                    let mut value = Box::new(i);
+                    while let Err(rejected) = producer.push(value) {
+                        value = rejected;
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            scope.spawn(move || {
+                let mut sum = 0;
+                let mut received = 0;
+                while received < count {
+                    match consumer.pop() {
+                        Some(value) => {
+                            sum += *value;
+                            received += 1;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                assert_eq!(sum, count * (count - 1) / 2);
+            });
+        });
+    }
+}
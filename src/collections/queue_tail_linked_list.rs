@@ -1,4 +1,4 @@
-use crate::tail_linked_list::TailLinkedList;
+use crate::collections::tail_linked_list::TailLinkedList;
 
 pub struct Queue<T> {
    data: TailLinkedList<T>,
@@ -24,6 +24,12 @@ impl<T> Queue<T> {
    }
 }
 
+impl<T> Default for Queue<T> {
+   fn default() -> Self {
+      Self::new()
+   }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,108 @@
+use std::alloc::{self, Layout};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::allocator::{Allocator, Global};
+
+/// An allocation of `capacity` uninitialised `T`s, shared by the collections
+/// that keep their elements in one contiguous buffer. It only tracks the
+/// memory: which slots hold elements, and dropping them, is up to its owner.
+/// The allocation itself is freed when the buffer is dropped.
+pub(crate) struct RawBuffer<T, A: Allocator = Global> {
+    ptr: NonNull<T>,
+    capacity: usize,
+    alloc: A,
+    _marker: PhantomData<T>,
+}
+
+impl<T, A: Allocator> RawBuffer<T, A> {
+    // Zero-sized types never touch the allocator, so they get a dangling
+    // pointer and an unlimited capacity from the start
+    const IS_ZST: bool = std::mem::size_of::<T>() == 0;
+
+    pub(crate) fn new_in(alloc: A) -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            capacity: if Self::IS_ZST { usize::MAX } else { 0 },
+            alloc,
+            _marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(crate) fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    // Doubles the capacity, or allocates `initial` slots for an empty buffer
+    pub(crate) fn grow(&mut self, initial: usize) {
+        if Self::IS_ZST {
+            // A ZST buffer already has usize::MAX capacity, so this is a length overflow
+            panic!("capacity overflow");
+        }
+
+        let new_capacity = if self.capacity == 0 {
+            initial
+        } else {
+            self.capacity.checked_mul(2).expect("capacity overflow")
+        };
+
+        self.reallocate(new_capacity);
+    }
+
+    // Moves the buffer to an allocation of exactly `new_capacity` slots. The
+    // allocator keeps the slots both allocations share in place.
+    pub(crate) fn reallocate(&mut self, new_capacity: usize) {
+        debug_assert!(new_capacity > 0);
+
+        if Self::IS_ZST {
+            return;
+        }
+
+        let new_layout = Layout::array::<T>(new_capacity).expect("capacity overflow");
+
+        let new_ptr = if self.capacity == 0 {
+            self.alloc.allocate(new_layout)
+        } else {
+            let old_layout = Layout::array::<T>(self.capacity).unwrap();
+            let old_ptr = self.ptr.cast::<u8>();
+
+            if new_capacity > self.capacity {
+                unsafe { self.alloc.grow(old_ptr, old_layout, new_layout) }
+            } else {
+                unsafe { self.alloc.shrink(old_ptr, old_layout, new_layout) }
+            }
+        };
+
+        self.ptr = match new_ptr {
+            Ok(ptr) => ptr.cast(),
+            Err(_) => alloc::handle_alloc_error(new_layout),
+        };
+        self.capacity = new_capacity;
+    }
+
+    // Hands the allocation back, leaving an empty buffer
+    pub(crate) fn free(&mut self) {
+        if Self::IS_ZST || self.capacity == 0 {
+            return;
+        }
+
+        let layout = Layout::array::<T>(self.capacity).unwrap();
+        unsafe { self.alloc.deallocate(self.ptr.cast(), layout) };
+        self.ptr = NonNull::dangling();
+        self.capacity = 0;
+    }
+}
+
+impl<T, A: Allocator> Drop for RawBuffer<T, A> {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
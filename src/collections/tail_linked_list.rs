@@ -0,0 +1,896 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::allocator::{box_in, unbox_in, Allocator, Global};
+use crate::collections::error::{IndexOutOfBounds, InsertError};
+
+struct Node<T> {
+    value: T,
+    next: Option<NonNull<Node<T>>>,
+}
+
+pub struct TailLinkedList<T, A: Allocator = Global> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    alloc: A,
+    // Tells the compiler the list owns its nodes, for drop check and variance
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> TailLinkedList<T> {
+    pub fn new() -> TailLinkedList<T> {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> TailLinkedList<T, A> {
+    pub fn new_in(alloc: A) -> TailLinkedList<T, A> {
+        Self { 
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = box_in(Node {
+            value,
+            next: self.head,
+        }, &self.alloc);
+
+        if self.tail.is_none() {
+            self.tail = Some(node); 
+        }
+
+        self.head = Some(node); 
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        if self.is_empty() {
+            self.push_front(value);
+            return;
+        }
+
+        let node = box_in(Node {
+            value,
+            next: None,
+        }, &self.alloc);
+
+        unsafe { self.tail.unwrap().as_mut().next = Some(node) };
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let node = self.head.take().unwrap();
+        unsafe { self.head = node.as_ref().next; }
+
+        if self.head.as_mut().is_none() {
+            self.tail = None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { unbox_in(node, &self.alloc).value })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        if unsafe { self.head.unwrap().as_ref().next.is_none() } {
+            return self.pop_front();
+        }
+    
+        let mut current = self.head;
+        while unsafe { current.unwrap().as_ref().next.unwrap().as_ref().next.is_some() } {
+            current = unsafe { current.unwrap().as_ref().next };
+        }
+            
+        let old_node = unsafe { current.unwrap().as_mut().next.take().unwrap() };
+        let old_tail = unsafe { unbox_in(old_node, &self.alloc) };
+        self.tail = current;
+        self.len -= 1;
+
+        Some(old_tail.value)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { &self.head.unwrap().as_ref().value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe { &self.tail.unwrap().as_ref().value })
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    // The node at `position`, or `None` past the end
+    fn node_at(&self, position: usize) -> Option<NonNull<Node<T>>> {
+        if self.len.checked_sub(1) == Some(position) {
+            return self.tail;
+        }
+
+        let mut current = self.head;
+        for _ in 0..position {
+            current = unsafe { current?.as_ref().next };
+        }
+        current
+    }
+
+    pub fn get(&self, position: usize) -> Option<&T> {
+        self.node_at(position).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn get_mut(&mut self, position: usize) -> Option<&mut T> {
+        self.node_at(position).map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn insert(&mut self, position: usize, value: T) {
+        if let Err(error) = self.try_insert(position, value) {
+            panic!("Insertion index ({}) is out of bounds len ({})", error.error.index, error.error.len);
+        }
+    }
+
+    /// Inserts `value` so it ends up at `position`, or hands it back if
+    /// `position` is past the end.
+    pub fn try_insert(&mut self, position: usize, value: T) -> Result<(), InsertError<T>> {
+        if position > self.len {
+            return Err(InsertError {
+                error: IndexOutOfBounds { index: position, len: self.len },
+                value,
+            });
+        }
+
+        // Both ends go through the push methods, which keep `tail` up to date
+        if position == 0 {
+            self.push_front(value);
+            return Ok(());
+        } else if position == self.len {
+            self.push_back(value);
+            return Ok(());
+        }
+
+        let mut prev = self.node_at(position - 1).unwrap();
+        unsafe {
+            let node = box_in(Node { value, next: prev.as_ref().next }, &self.alloc);
+            prev.as_mut().next = Some(node);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, position: usize) -> T {
+        match self.try_remove(position) {
+            Ok(value) => value,
+            Err(error) => panic!("Removal index ({}) is out of bounds len ({})", error.index, error.len),
+        }
+    }
+
+    pub fn try_remove(&mut self, position: usize) -> Result<T, IndexOutOfBounds> {
+        if position >= self.len {
+            return Err(IndexOutOfBounds { index: position, len: self.len });
+        }
+
+        if position == 0 {
+            return Ok(self.pop_front().unwrap());
+        }
+
+        let mut prev = self.node_at(position - 1).unwrap();
+        let node = unsafe { unbox_in(prev.as_mut().next.take().unwrap(), &self.alloc) };
+        unsafe { prev.as_mut().next = node.next };
+
+        // Removing the last node makes the one before it the new tail
+        if node.next.is_none() {
+            self.tail = Some(prev);
+        }
+
+        self.len -= 1;
+        Ok(node.value)
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.next = node.next;
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    len: usize,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.next.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.next = node.next;
+            self.len -= 1;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    list: TailLinkedList<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> IntoIterator for TailLinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a TailLinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut TailLinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for TailLinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for TailLinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for TailLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for TailLinkedList<T, A> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.extend(self.iter().cloned());
+        list
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for TailLinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for TailLinkedList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for TailLinkedList<T, A> {}
+
+impl<T: Hash, A: Allocator> Hash for TailLinkedList<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashing the length first keeps lists of lists from colliding when
+        // their elements are regrouped
+        state.write_usize(self.len);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+// Nodes are freed one at a time so long lists can't overflow the stack
+impl<T, A: Allocator> Drop for TailLinkedList<T, A> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+// `tail` only ever aliases a node already owned through `head`, and it is
+// never handed out, so the list owns its nodes exclusively, just like a
+// chain of boxes would
+unsafe impl<T: Send, A: Allocator + Send> Send for TailLinkedList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for TailLinkedList<T, A> {}
+
+impl<T> Default for TailLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*; 
+
+    #[test]
+    fn test_new() {
+        let list: TailLinkedList<i32> = TailLinkedList::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut list = TailLinkedList::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(unsafe { list.tail.unwrap().as_ref().value }, 1);
+        assert!(!list.is_empty());
+        assert_eq!(list.front(), Some(&3));
+        assert_eq!(list.back(), Some(&1));
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(unsafe { list.tail.unwrap().as_ref().value }, 3);
+        assert!(!list.is_empty());
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(unsafe { list.head.unwrap().as_ref().value }, 2);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(unsafe { list.tail.unwrap().as_ref().value }, 2);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.remove(1), 2);
+        assert_eq!(list.remove(0), 1);
+        assert_eq!(list.remove(0), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_out_of_bounds() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        list.remove(3);
+    }
+
+    #[test]
+    fn test_remove_front() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.remove(0), 1);
+        assert_eq!(list.remove(0), 2);
+        assert_eq!(list.remove(0), 3);
+    }
+
+    #[test]
+    fn test_remove_back() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.remove(2), 3);
+        assert_eq!(list.remove(1), 2);
+        assert_eq!(list.remove(0), 1);
+    }
+
+    #[test]
+    fn test_front() {
+        let mut list = TailLinkedList::new();
+        assert_eq!(list.front(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.front(), Some(&1));
+    }
+
+    #[test]
+    fn test_back() {
+        let mut list = TailLinkedList::new();
+        assert_eq!(list.back(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn test_counting_allocator() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = TailLinkedList::new_in(&counter);
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(0);
+        assert_eq!(counter.live_allocations(), 4);
+
+        assert_eq!(list.remove(1), 1);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    #[test]
+    fn test_bump_allocator() {
+        use crate::allocator::BumpAllocator;
+
+        let arena = BumpAllocator::new(1024);
+        let mut list = TailLinkedList::new_in(&arena);
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.back(), Some(&9));
+        while list.pop_front().is_some() {}
+    }
+
+    // Walks the list and checks `tail` still points at its last node
+    fn assert_tail_is_last<T, A: Allocator>(list: &TailLinkedList<T, A>) {
+        let mut last = None;
+        let mut current = list.head;
+        let mut len = 0;
+        while let Some(node) = current {
+            last = Some(node);
+            current = unsafe { node.as_ref().next };
+            len += 1;
+        }
+
+        assert_eq!(list.tail, last);
+        assert_eq!(list.len(), len);
+    }
+
+    #[test]
+    fn test_drop_frees_every_node() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = TailLinkedList::new_in(&counter);
+        for i in 0..100 {
+            list.push_back(i);
+        }
+        assert_eq!(counter.live_allocations(), 100);
+
+        drop(list);
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    #[test]
+    fn test_drop_runs_destructors() {
+        use std::rc::Rc;
+
+        let value = Rc::new(());
+        let mut list = TailLinkedList::new();
+        for _ in 0..10 {
+            list.push_back(Rc::clone(&value));
+        }
+        assert_eq!(Rc::strong_count(&value), 11);
+
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_long_list_drop() {
+        let count = crate::collections::stress_count(100_000);
+        let mut list = TailLinkedList::new();
+        for i in 0..count {
+            list.push_back(i);
+        }
+    }
+
+    #[test]
+    fn test_pop_back_rewrites_tail() {
+        let mut list = TailLinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        for expected in (2..5).rev() {
+            assert_eq!(list.pop_back(), Some(expected));
+            assert_tail_is_last(&list);
+        }
+
+        // Pushing after popping must link onto the new tail, not a freed node
+        list.push_back(10);
+        assert_tail_is_last(&list);
+        assert_eq!(list.back(), Some(&10));
+
+        while list.pop_back().is_some() {
+            assert_tail_is_last(&list);
+        }
+        assert_eq!(list.tail, None);
+
+        list.push_back(20);
+        assert_tail_is_last(&list);
+        assert_eq!(list.front(), Some(&20));
+    }
+
+    #[test]
+    fn test_remove_rewrites_tail() {
+        let mut list = TailLinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        assert_eq!(list.remove(4), 4);
+        assert_tail_is_last(&list);
+        assert_eq!(list.remove(1), 1);
+        assert_tail_is_last(&list);
+        assert_eq!(list.back(), Some(&3));
+
+        list.push_back(5);
+        assert_tail_is_last(&list);
+
+        while !list.is_empty() {
+            list.remove(list.len() - 1);
+            assert_tail_is_last(&list);
+        }
+
+        list.push_front(6);
+        list.push_back(7);
+        assert_tail_is_last(&list);
+        assert_eq!(list.back(), Some(&7));
+    }
+
+    #[test]
+    fn test_pop_front_clears_tail() {
+        let mut list = TailLinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.tail, None);
+
+        list.push_back(2);
+        assert_tail_is_last(&list);
+        assert_eq!(list.front(), Some(&2));
+    }
+
+    #[test]
+    fn test_send_sync_and_variance() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<TailLinkedList<i32>>();
+
+        // Compiles only if the list is covariant in `T`, like `Box<T>`
+        fn shorten<'a>(list: TailLinkedList<&'static str>) -> TailLinkedList<&'a str> {
+            list
+        }
+
+        let mut list = TailLinkedList::new();
+        list.push_back("a");
+        let list = shorten(list);
+        assert_eq!(list.front(), Some(&"a"));
+    }
+
+    #[test]
+    fn test_send_across_threads() {
+        let mut list = TailLinkedList::new();
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        let sum = std::thread::spawn(move || {
+            let mut sum = 0;
+            while let Some(value) = list.pop_front() {
+                sum += value;
+            }
+            sum
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(sum, 45);
+    }
+
+    #[test]
+    fn test_collect_and_eq() {
+        let list: TailLinkedList<i32> = (1..=3).collect();
+        let same: TailLinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let other: TailLinkedList<i32> = (1..=4).collect();
+
+        assert_eq!(list, same);
+        assert_ne!(list, other);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn test_for_loops() {
+        let mut list: TailLinkedList<i32> = (1..=3).collect();
+
+        for value in &mut list {
+            *value *= 2;
+        }
+
+        let mut seen = Vec::new();
+        for value in &list {
+            seen.push(*value);
+        }
+        assert_eq!(seen, vec![2, 4, 6]);
+
+        let mut owned = list.into_iter();
+        assert_eq!(owned.len(), 3);
+        assert_eq!(owned.next(), Some(2));
+        assert_eq!(owned.collect::<Vec<_>>(), vec![4, 6]);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list = TailLinkedList::new();
+        list.extend(vec![1, 2]);
+        list.extend(&[3, 4]);
+        list.push_back(5);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.back(), Some(&5));
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn test_clone_is_independent() {
+        let list: TailLinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut copy = list.clone();
+        copy.push_back("c".to_string());
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(copy.len(), 3);
+        assert_eq!(copy.iter().take(2).collect::<Vec<_>>(), list.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_debug() {
+        let list: TailLinkedList<i32> = (1..=3).collect();
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", TailLinkedList::<i32>::new()), "[]");
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: TailLinkedList<i32> = (1..=3).collect();
+        let b: TailLinkedList<i32> = (1..=3).collect();
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn test_into_iter_drops_the_rest() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut list = TailLinkedList::new_in(&counter);
+        list.extend(0..10);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    #[test]
+    fn test_get_and_get_mut() {
+        let mut list: TailLinkedList<i32> = (0..5).collect();
+
+        assert_eq!(list.get(0), Some(&0));
+        assert_eq!(list.get(4), Some(&4));
+        assert_eq!(list.get(5), None);
+
+        *list.get_mut(2).unwrap() = 20;
+        *list.get_mut(4).unwrap() = 40;
+        assert_eq!(list.get_mut(5), None);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 20, 3, 40]);
+
+        assert_eq!(list.get(usize::MAX), None);
+        assert_eq!(list.get_mut(usize::MAX), None);
+        let mut empty: TailLinkedList<i32> = TailLinkedList::new();
+        assert_eq!(empty.get(usize::MAX), None);
+        assert_eq!(empty.get_mut(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list = TailLinkedList::new();
+        list.insert(0, 2);
+        assert_tail_is_last(&list);
+        list.insert(0, 0);
+        assert_tail_is_last(&list);
+        list.insert(1, 1);
+        assert_tail_is_last(&list);
+        list.insert(3, 4);
+        assert_tail_is_last(&list);
+        list.insert(3, 3);
+        assert_tail_is_last(&list);
+
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(list.back(), Some(&4));
+        list.push_back(5);
+        assert_eq!(list.get(5), Some(&5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Insertion index (4) is out of bounds len (3)")]
+    fn test_insert_out_of_bounds() {
+        let mut list: TailLinkedList<i32> = (0..3).collect();
+        list.insert(4, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Removal index (3) is out of bounds len (3)")]
+    fn test_remove_out_of_bounds_message() {
+        let mut list: TailLinkedList<i32> = (0..3).collect();
+        list.remove(3);
+    }
+
+    #[test]
+    fn test_try_insert_and_try_remove() {
+        let mut list: TailLinkedList<String> = ["a", "c"].iter().map(|s| s.to_string()).collect();
+
+        assert_eq!(list.try_insert(1, "b".to_string()), Ok(()));
+        let error = list.try_insert(4, "x".to_string()).unwrap_err();
+        assert_eq!(error.error, IndexOutOfBounds { index: 4, len: 3 });
+        assert_eq!(error.to_string(), "insertion index (4) is out of bounds len (3)");
+        assert_eq!(error.into_value(), "x");
+
+        assert_eq!(list.try_remove(3), Err(IndexOutOfBounds { index: 3, len: 3 }));
+        assert_eq!(list.try_remove(2), Ok("c".to_string()));
+        assert_tail_is_last(&list);
+        assert_eq!(list.try_remove(0), Ok("a".to_string()));
+        assert_tail_is_last(&list);
+        assert_eq!(list.try_remove(0), Ok("b".to_string()));
+        assert_tail_is_last(&list);
+        assert_eq!(list.try_remove(0), Err(IndexOutOfBounds { index: 0, len: 0 }));
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,1108 @@
+use std::iter::FromIterator;
+use std::mem::ManuallyDrop;
+use std::ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds};
+use std::slice::SliceIndex;
+
+use super::raw_buffer::RawBuffer;
+use crate::allocator::{Allocator, Global};
+
+pub struct Vector<T, A: Allocator = Global> {
+    len: usize,
+    buf: RawBuffer<T, A>,
+}
+
+impl<T> Vector<T> {
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            len: 0,
+            buf: RawBuffer::new_in(alloc),
+        }
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let mut vector = Self::new_in(alloc);
+        vector.reserve_exact(capacity);
+        vector
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.buf.allocator()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.len == self.buf.capacity() {
+            self.buf.grow(1);
+        }
+
+        unsafe {
+            let ptr = self.buf.ptr().add(self.len);
+            std::ptr::write(ptr, item);
+        }
+
+        self.len += 1;
+    }
+
+    pub fn insert(&mut self, position: usize, item: T) {
+        if position > self.len {
+            panic!("Insertion index ({}) is out of bounds len ({})", position, self.len);
+        } 
+
+        if self.len == self.buf.capacity() {
+            self.buf.grow(1);
+        }
+
+        unsafe {
+            std::ptr::copy(
+                self.buf.ptr().add(position),
+                self.buf.ptr().add(position + 1),
+                self.len - position,
+                );
+
+            std::ptr::write(self.buf.ptr().add(position), item);
+
+            self.len += 1;
+        }
+    }
+
+    pub fn prepend(&mut self, item: T) {
+        self.insert(0, item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        unsafe {
+            Some(std::ptr::read(self.buf.ptr().add(self.len)))
+        }
+    }
+
+    pub fn remove(&mut self, position: usize) -> T {
+        if position >= self.len {
+            panic!("Removal index ({}) is out of bounds len ({})", position, self.len);
+        }
+
+        unsafe {
+            self.len -= 1;
+            let item = std::ptr::read(self.buf.ptr().add(position));
+
+            std::ptr::copy(
+                self.buf.ptr().add(position + 1),
+                self.buf.ptr().add(position),
+                self.len - position,
+            );
+            
+            item
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.buf.ptr(), self.len) }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let removed = self.len - len;
+        // Shrink first so a panicking destructor can't cause a double drop
+        self.len = len;
+        unsafe {
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.buf.ptr().add(len), removed));
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn append(&mut self, other: &mut Vector<T, A>) {
+        let count = other.len;
+        self.reserve(count);
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(other.buf.ptr(), self.buf.ptr().add(self.len), count);
+        }
+
+        // The elements now belong to `self`, so `other` must forget them
+        other.len = 0;
+        self.len += count;
+    }
+
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.dedup_or_retain(|_, current| !f(current));
+    }
+
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        if self.len == 0 {
+            return;
+        }
+
+        self.dedup_or_retain(|kept, current| match kept {
+            Some(kept) => same_bucket(current, kept),
+            None => false,
+        });
+    }
+
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    // Walks the elements once, dropping those `remove` rejects and sliding
+    // the rest down. `remove` also sees the last element kept so far. The
+    // length is zeroed while this runs, so a panic leaks instead of double
+    // dropping.
+    fn dedup_or_retain<F: FnMut(Option<&mut T>, &mut T) -> bool>(&mut self, mut remove: F) {
+        let len = self.len;
+        self.len = 0;
+        let mut kept = 0;
+
+        for read in 0..len {
+            unsafe {
+                let current = self.buf.ptr().add(read);
+                let last_kept = if kept == 0 {
+                    None
+                } else {
+                    Some(&mut *self.buf.ptr().add(kept - 1))
+                };
+
+                if remove(last_kept, &mut *current) {
+                    std::ptr::drop_in_place(current);
+                } else {
+                    if read != kept {
+                        std::ptr::copy_nonoverlapping(current, self.buf.ptr().add(kept), 1);
+                    }
+                    kept += 1;
+                }
+            }
+        }
+
+        self.len = kept;
+    }
+
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("Drain start ({}) is out of bounds len ({})", n, self.len)),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("Drain end ({}) is out of bounds len ({})", n, self.len)),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+
+        if start > end {
+            panic!("Drain start ({}) is greater than end ({})", start, end);
+        }
+        if end > self.len {
+            panic!("Drain end ({}) is out of bounds len ({})", end, self.len);
+        }
+
+        let tail_len = self.len - end;
+        // Elements from `start` onwards are owned by the drain until it is dropped
+        self.len = start;
+
+        Drain {
+            vector: self,
+            front: start,
+            back: end,
+            tail_start: end,
+            tail_len,
+        }
+    }
+
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.buf.capacity() {
+            return;
+        }
+
+        let doubled = self.buf.capacity().saturating_mul(2);
+        self.buf.reallocate(required.max(doubled));
+    }
+
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.buf.capacity() {
+            return;
+        }
+
+        self.buf.reallocate(required);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        if self.buf.capacity() == self.len {
+            return;
+        }
+
+        if self.len == 0 {
+            self.buf.free();
+        } else {
+            self.buf.reallocate(self.len);
+        }
+    }
+}
+
+// Vector owns its elements just like Vec does, so it is as thread-safe as T
+// and the allocator it carries
+unsafe impl<T: Send, A: Allocator + Send> Send for Vector<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Vector<T, A> {}
+
+impl<T, A: Allocator> Drop for Vector<T, A> {
+    fn drop(&mut self) {
+        // The buffer frees the allocation itself once the elements are gone
+        unsafe { std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.buf.ptr(), self.len)) };
+    }
+}
+
+impl<T, A: Allocator + Clone> Vector<T, A> {
+    pub fn split_off(&mut self, at: usize) -> Vector<T, A> {
+        if at > self.len {
+            panic!("Split index ({}) is out of bounds len ({})", at, self.len);
+        }
+
+        let count = self.len - at;
+        let mut other = Vector::with_capacity_in(count, self.buf.allocator().clone());
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.buf.ptr().add(at), other.buf.ptr(), count);
+        }
+
+        self.len = at;
+        other.len = count;
+        other
+    }
+}
+
+impl<T, A: Allocator> Deref for Vector<T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> DerefMut for Vector<T, A> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: Allocator> AsRef<[T]> for Vector<T, A> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> AsMut<[T]> for Vector<T, A> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, A: Allocator, I: SliceIndex<[T]>> Index<I> for Vector<T, A> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, A: Allocator, I: SliceIndex<[T]>> IndexMut<I> for Vector<T, A> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    buf: RawBuffer<T, A>,
+    front: usize,
+    back: usize,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let item = unsafe { std::ptr::read(self.buf.ptr().add(self.front)) };
+        self.front += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(unsafe { std::ptr::read(self.buf.ptr().add(self.back)) })
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        for _ in &mut *self {}
+    }
+}
+
+// IntoIter owns the elements it hasn't yielded yet, so it is as thread-safe
+// as T and the allocator it carries, just like Vector
+unsafe impl<T: Send, A: Allocator + Send> Send for IntoIter<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for IntoIter<T, A> {}
+
+pub struct Drain<'a, T, A: Allocator = Global> {
+    vector: &'a mut Vector<T, A>,
+    front: usize,
+    back: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let item = unsafe { std::ptr::read(self.vector.buf.ptr().add(self.front)) };
+        self.front += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(unsafe { std::ptr::read(self.vector.buf.ptr().add(self.back)) })
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        for _ in &mut *self {}
+
+        let start = self.vector.len;
+        if self.tail_len > 0 {
+            unsafe {
+                std::ptr::copy(
+                    self.vector.buf.ptr().add(self.tail_start),
+                    self.vector.buf.ptr().add(start),
+                    self.tail_len,
+                );
+            }
+        }
+
+        self.vector.len = start + self.tail_len;
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for Vector<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        let vector = ManuallyDrop::new(self);
+
+        IntoIter {
+            // The vector is never dropped, so the buffer is moved out exactly once
+            buf: unsafe { std::ptr::read(&vector.buf) },
+            front: 0,
+            back: vector.len,
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a Vector<T, A> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Vector<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Vector<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Vector::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let v: Vector<i32> = Vector::new();
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.capacity(), 0);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.capacity(), 4);
+        assert!(!v.is_empty());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        v.insert(1, 4);
+
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.capacity(), 4);
+        assert_eq!(v[0], 1);
+        assert_eq!(v[1], 4);
+        assert_eq!(v[2], 2);
+        assert_eq!(v[3], 3);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        v.prepend(4);
+
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.capacity(), 4);
+        assert_eq!(v[0], 4);
+        assert_eq!(v[1], 1);
+        assert_eq!(v[2], 2);
+        assert_eq!(v[3], 3);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        assert_eq!(v.pop(), Some(3));
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(v.pop(), Some(1));
+        assert_eq!(v.pop(), None);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        assert_eq!(v.remove(1), 2);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v.capacity(), 4);
+        assert_eq!(v[0], 1);
+        assert_eq!(v[1], 3);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        assert_eq!(v[0], 1);
+        assert_eq!(v[1], 2);
+        assert_eq!(v[2], 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        let _ = v[3];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_out_of_bounds() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        v.insert(4, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_out_of_bounds() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        v.remove(3);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        let items: Vec<&i32> = v.iter().collect();
+        assert_eq!(items, vec![&1, &2, &3]);
+
+        let empty: Vector<i32> = Vector::new();
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        for item in v.iter_mut() {
+            *item *= 10;
+        }
+
+        assert_eq!(v[0], 10);
+        assert_eq!(v[1], 20);
+        assert_eq!(v[2], 30);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut v = Vector::new();
+        v.push(1);
+        v.push(2);
+        v.push(3);
+
+        let mut iter = v.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<IntoIter<i32>>();
+
+        let iter = (0..10).collect::<Vector<i32>>().into_iter();
+        let sum = std::thread::spawn(move || iter.sum::<i32>()).join().unwrap();
+        assert_eq!(sum, 45);
+    }
+
+    #[test]
+    fn test_into_iter_drops_remaining() {
+        use std::rc::Rc;
+
+        let item = Rc::new(());
+        let mut v = Vector::new();
+        for _ in 0..5 {
+            v.push(Rc::clone(&item));
+        }
+
+        let mut iter = v.into_iter();
+        iter.next();
+        iter.next();
+        assert_eq!(Rc::strong_count(&item), 4);
+
+        drop(iter);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut v: Vector<i32> = (0..6).collect();
+
+        let drained: Vec<i32> = v.drain(1..4).collect();
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[0], 0);
+        assert_eq!(v[1], 4);
+        assert_eq!(v[2], 5);
+
+        let drained: Vec<i32> = v.drain(..).collect();
+        assert_eq!(drained, vec![0, 4, 5]);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_drain_partially_consumed() {
+        use std::rc::Rc;
+
+        let item = Rc::new(());
+        let mut v = Vector::new();
+        for _ in 0..5 {
+            v.push(Rc::clone(&item));
+        }
+
+        let mut drain = v.drain(1..=3);
+        drain.next();
+        drop(drain);
+
+        assert_eq!(v.len(), 2);
+        assert_eq!(Rc::strong_count(&item), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_drain_out_of_bounds() {
+        let mut v: Vector<i32> = (0..3).collect();
+        v.drain(1..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Drain end")]
+    fn test_drain_inclusive_end_at_max() {
+        let mut v: Vector<i32> = (0..3).collect();
+        v.drain(0..=usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Drain start")]
+    fn test_drain_excluded_start_at_max() {
+        use std::ops::Bound;
+
+        let mut v: Vector<i32> = (0..3).collect();
+        v.drain((Bound::Excluded(usize::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut v: Vector<i32> = (1..4).collect();
+        v.extend(vec![4, 5]);
+
+        assert_eq!(v.len(), 5);
+        let mut sum = 0;
+        for item in &v {
+            sum += item;
+        }
+        assert_eq!(sum, 15);
+    }
+
+    // Counts drops so the tests can catch both leaks and double frees
+    struct DropCounter(std::rc::Rc<std::cell::Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    fn drop_counters(n: usize) -> (std::rc::Rc<std::cell::Cell<usize>>, Vector<DropCounter>) {
+        let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+        let mut v = Vector::new();
+        for _ in 0..n {
+            v.push(DropCounter(std::rc::Rc::clone(&drops)));
+        }
+
+        (drops, v)
+    }
+
+    #[test]
+    fn test_drop_drops_every_element_once() {
+        let (drops, v) = drop_counters(10);
+        drop(v);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_remove_and_pop_drop_once() {
+        let (drops, mut v) = drop_counters(5);
+
+        drop(v.remove(2));
+        drop(v.pop());
+        assert_eq!(drops.get(), 2);
+
+        drop(v);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_zero_sized_types() {
+        let mut v = Vector::new();
+        assert_eq!(v.capacity(), usize::MAX);
+
+        for _ in 0..100 {
+            v.push(());
+        }
+        v.insert(50, ());
+
+        assert_eq!(v.len(), 101);
+        assert_eq!(v.remove(0), ());
+        assert_eq!(v.pop(), Some(()));
+        assert_eq!(v.iter().count(), 99);
+        assert_eq!(v.into_iter().count(), 99);
+    }
+
+    #[test]
+    fn test_zero_sized_drop() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Zst;
+
+        impl Drop for Zst {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let mut v = Vector::new();
+        for _ in 0..3 {
+            v.push(Zst);
+        }
+        drop(v.pop());
+        drop(v);
+
+        assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let v: Vector<i32> = Vector::with_capacity(10);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.capacity(), 10);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut v: Vector<i32> = Vector::new();
+        v.reserve(5);
+        assert_eq!(v.capacity(), 5);
+
+        v.push(1);
+        v.reserve(2);
+        assert_eq!(v.capacity(), 5);
+
+        v.reserve(10);
+        assert_eq!(v.capacity(), 11);
+        assert_eq!(v[0], 1);
+    }
+
+    #[test]
+    fn test_reserve_exact() {
+        let mut v: Vector<i32> = (0..4).collect();
+        v.reserve_exact(1);
+        assert_eq!(v.capacity(), 5);
+        assert_eq!(v[3], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_reserve_overflow() {
+        let mut v = Vector::new();
+        v.push(1u8);
+        v.reserve(usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_reserve_layout_overflow() {
+        let mut v: Vector<u64> = Vector::new();
+        v.reserve(usize::MAX / 4);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let (drops, mut v) = drop_counters(5);
+        v.reserve(100);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 5);
+
+        v.drain(..);
+        assert_eq!(drops.get(), 5);
+
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), 0);
+
+        v.push(DropCounter(std::rc::Rc::clone(&drops)));
+        assert_eq!(v.len(), 1);
+    }
+
+    #[test]
+    fn test_counting_allocator() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut v = Vector::new_in(&counter);
+        for i in 0..100 {
+            v.push(i);
+        }
+        assert_eq!(counter.live_allocations(), 1);
+        assert_eq!(counter.bytes_in_use(), 128 * std::mem::size_of::<i32>());
+
+        v.shrink_to_fit();
+        assert_eq!(counter.bytes_in_use(), 100 * std::mem::size_of::<i32>());
+
+        drop(v);
+        assert_eq!(counter.live_allocations(), 0);
+        assert_eq!(counter.bytes_in_use(), 0);
+    }
+
+    #[test]
+    fn test_counting_allocator_into_iter() {
+        use crate::allocator::CountingAllocator;
+
+        let counter = CountingAllocator::new();
+        let mut v = Vector::new_in(&counter);
+        v.extend(0..10);
+
+        let mut iter = v.into_iter();
+        iter.next();
+        drop(iter);
+
+        assert_eq!(counter.live_allocations(), 0);
+    }
+
+    #[test]
+    fn test_bump_allocator() {
+        use crate::allocator::BumpAllocator;
+
+        let arena = BumpAllocator::new(1024);
+        let mut v = Vector::new_in(&arena);
+        for i in 0..64u32 {
+            v.push(i);
+        }
+
+        // The buffer is always the newest block, so every doubling happens in place
+        assert_eq!(arena.used(), 64 * std::mem::size_of::<u32>());
+        assert_eq!(v[63], 63);
+    }
+
+    #[test]
+    fn test_deref_slice() {
+        let mut v: Vector<i32> = vec![3, 1, 2].into_iter().collect();
+
+        v.sort();
+        assert_eq!(v.as_slice(), &[1, 2, 3]);
+        assert_eq!(v.first(), Some(&1));
+        assert_eq!(v.binary_search(&2), Ok(1));
+        assert_eq!(v.windows(2).count(), 2);
+        assert_eq!(v.chunks(2).count(), 2);
+        assert!(v.contains(&3));
+    }
+
+    #[test]
+    fn test_index_ranges() {
+        let mut v: Vector<i32> = (0..5).collect();
+
+        assert_eq!(&v[1..3], &[1, 2]);
+        assert_eq!(&v[..2], &[0, 1]);
+        assert_eq!(&v[3..], &[3, 4]);
+        assert_eq!(&v[1..=2], &[1, 2]);
+        assert_eq!(&v[..=1], &[0, 1]);
+        assert_eq!(v[..].len(), 5);
+
+        v[0] = 10;
+        v[1..3].copy_from_slice(&[20, 30]);
+        assert_eq!(v.as_slice(), &[10, 20, 30, 3, 4]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let (drops, mut v) = drop_counters(5);
+
+        v.truncate(2);
+        assert_eq!(v.len(), 2);
+        assert_eq!(drops.get(), 3);
+
+        v.truncate(10);
+        assert_eq!(v.len(), 2);
+
+        v.clear();
+        assert!(v.is_empty());
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut v: Vector<i32> = (0..5).collect();
+        let tail = v.split_off(2);
+
+        assert_eq!(v.as_slice(), &[0, 1]);
+        assert_eq!(tail.as_slice(), &[2, 3, 4]);
+
+        let empty = v.split_off(2);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_off_out_of_bounds() {
+        let mut v: Vector<i32> = (0..5).collect();
+        v.split_off(6);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut v: Vector<i32> = (0..3).collect();
+        let mut other: Vector<i32> = (3..6).collect();
+
+        v.append(&mut other);
+        assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4, 5]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut v: Vector<i32> = (0..10).collect();
+        v.retain(|x| x % 3 == 0);
+        assert_eq!(v.as_slice(), &[0, 3, 6, 9]);
+
+        let (drops, mut v) = drop_counters(6);
+        let mut keep = false;
+        v.retain(|_| {
+            keep = !keep;
+            keep
+        });
+        assert_eq!(v.len(), 3);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut v: Vector<i32> = vec![1, 1, 2, 3, 3, 3, 1, 4, 4].into_iter().collect();
+        v.dedup();
+        assert_eq!(v.as_slice(), &[1, 2, 3, 1, 4]);
+
+        let mut v: Vector<i32> = vec![10, 11, 20, 21, 30].into_iter().collect();
+        v.dedup_by_key(|x| *x / 10);
+        assert_eq!(v.as_slice(), &[10, 20, 30]);
+    }
+
+    #[test]
+    fn test_binary_search_module() {
+        use crate::algorithms::search::binary_search;
+
+        let v: Vector<usize> = (1..10).collect();
+        for i in 1..10 {
+            assert_eq!(binary_search(&v, i), Some(i - 1));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod allocator;
+pub mod collections;
+pub mod algorithms;
+pub mod sync;
@@ -1,12 +1,8 @@
-mod vector;
-mod linked_list;
-mod tail_linked_list;
-mod queue_tail_linked_list;
-mod queue_fixed_array;
-mod hash_table_linear_probing;
-mod binary_search;
-mod binary_search_recursive; 
+use practice_rust::algorithms::search::binary_search;
+use practice_rust::collections::vector::Vector;
 
 fn main() {
-    println!("Hello, world!");
+    let vector: Vector<usize> = (1..10).collect();
+
+    println!("Vector of {} elements, 5 found at {:?}", vector.len(), binary_search(&vector, 5));
 }
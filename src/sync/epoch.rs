@@ -0,0 +1,341 @@
+//! Epoch-based memory reclamation for lock-free structures.
+//!
+//! A thread `pin`s itself before reading shared pointers and stays pinned
+//! while its `Guard` lives. Memory unlinked from a structure is handed to
+//! `Guard::defer_destroy` instead of being freed, and is only freed once
+//! every thread pinned at the time has unpinned.
+//!
+//! The global epoch only advances when every pinned thread has caught up
+//! with it. Garbage retired in epoch `e` was unlinked before any thread that
+//! pins in epoch `e + 1` could reach it, and the threads that could still see
+//! it were pinned in `e` or earlier, so once the global epoch reaches `e + 2`
+//! none of them can still be pinned and the garbage can be freed.
+
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{fence, AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+// How much garbage a thread collects before trying to free some of it
+const COLLECT_THRESHOLD: usize = 64;
+
+static GLOBAL_EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+// Every thread that has ever pinned, as a push-only linked list. Entries are
+// never freed; a thread exiting marks its entry free for the next one.
+static PARTICIPANTS: AtomicPtr<Participant> = AtomicPtr::new(ptr::null_mut());
+
+// Garbage left behind by threads that exited before it could be freed
+static ORPHANS: Mutex<Vec<Retired>> = Mutex::new(Vec::new());
+
+struct Participant {
+    // The epoch the thread pinned in, shifted left, with the lowest bit set
+    // while it is pinned
+    epoch: AtomicUsize,
+    in_use: AtomicBool,
+    next: *mut Participant,
+}
+
+// An allocation waiting to be freed, with the function that frees it
+struct Retired {
+    epoch: usize,
+    ptr: *mut u8,
+    free: unsafe fn(*mut u8),
+}
+
+// Garbage is no longer reachable by any thread but the one freeing it
+unsafe impl Send for Retired {}
+
+impl Retired {
+    fn is_expired(&self, global: usize) -> bool {
+        global.wrapping_sub(self.epoch) >= 2
+    }
+}
+
+struct Handle {
+    participant: &'static Participant,
+    // Number of live guards, so nested pins only unpin at the outermost one
+    guards: Cell<usize>,
+    garbage: RefCell<Vec<Retired>>,
+}
+
+impl Handle {
+    fn register() -> Handle {
+        let mut current = PARTICIPANTS.load(Ordering::Acquire);
+
+        // Reuse an entry left behind by an exited thread if there is one
+        while let Some(participant) = unsafe { current.as_ref() } {
+            if participant
+                .in_use
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return Handle::new(participant);
+            }
+            current = participant.next;
+        }
+
+        let participant = Box::leak(Box::new(Participant {
+            epoch: AtomicUsize::new(0),
+            in_use: AtomicBool::new(true),
+            next: ptr::null_mut(),
+        }));
+
+        let mut head = PARTICIPANTS.load(Ordering::Relaxed);
+        loop {
+            participant.next = head;
+            match PARTICIPANTS.compare_exchange_weak(head, participant, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return Handle::new(participant),
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    fn new(participant: &'static Participant) -> Handle {
+        Handle {
+            participant,
+            guards: Cell::new(0),
+            garbage: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn collect(&self) {
+        try_advance();
+        let global = GLOBAL_EPOCH.load(Ordering::Acquire);
+
+        let expired: Vec<Retired> = {
+            let mut garbage = self.garbage.borrow_mut();
+            let (expired, pending) = garbage.drain(..).partition(|retired| retired.is_expired(global));
+            *garbage = pending;
+            expired
+        };
+        free_all(expired);
+
+        // Only help with the orphans if nobody else is already at it
+        if let Ok(mut orphans) = ORPHANS.try_lock() {
+            let (expired, pending) = orphans.drain(..).partition(|retired| retired.is_expired(global));
+            *orphans = pending;
+            drop(orphans);
+            free_all(expired);
+        }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        let garbage = std::mem::take(self.garbage.get_mut());
+        if !garbage.is_empty() {
+            ORPHANS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).extend(garbage);
+        }
+
+        self.participant.epoch.store(0, Ordering::Release);
+        self.participant.in_use.store(false, Ordering::Release);
+    }
+}
+
+fn free_all(garbage: Vec<Retired>) {
+    for retired in garbage {
+        unsafe { (retired.free)(retired.ptr) };
+    }
+}
+
+// Moves the global epoch on if every pinned thread has seen the current one
+fn try_advance() {
+    let global = GLOBAL_EPOCH.load(Ordering::Relaxed);
+    // Pairs with the fence in `pin`, so a thread pinning right now either
+    // shows up below or sees the advanced epoch
+    fence(Ordering::SeqCst);
+
+    let mut current = PARTICIPANTS.load(Ordering::Acquire);
+    while let Some(participant) = unsafe { current.as_ref() } {
+        let epoch = participant.epoch.load(Ordering::Relaxed);
+        if epoch & 1 == 1 && epoch >> 1 != global {
+            return;
+        }
+        current = participant.next;
+    }
+
+    let _ = GLOBAL_EPOCH.compare_exchange(global, global.wrapping_add(1), Ordering::Release, Ordering::Relaxed);
+}
+
+thread_local! {
+    static HANDLE: Handle = Handle::register();
+}
+
+/// Keeps the current thread pinned while it lives. Pointers loaded from a
+/// structure that retires through this module stay valid until it drops.
+pub struct Guard {
+    // Guards belong to the thread that pinned
+    _marker: PhantomData<*const ()>,
+}
+
+/// Pins the current thread.
+pub fn pin() -> Guard {
+    HANDLE.with(|handle| {
+        let guards = handle.guards.get();
+        handle.guards.set(guards + 1);
+
+        if guards == 0 {
+            let global = GLOBAL_EPOCH.load(Ordering::Relaxed);
+            handle.participant.epoch.store(global << 1 | 1, Ordering::Relaxed);
+            // The pin must be visible before any shared pointer is read
+            fence(Ordering::SeqCst);
+        }
+    });
+
+    Guard { _marker: PhantomData }
+}
+
+impl Guard {
+    /// Frees `ptr`, which came from `Box::into_raw`, once no pinned thread
+    /// can still be reading it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must already be unreachable for threads that pin from now on,
+    /// and must not be retired twice.
+    pub unsafe fn defer_destroy<T>(&self, ptr: *mut T) {
+        unsafe fn free<T>(ptr: *mut u8) {
+            drop(Box::from_raw(ptr as *mut T));
+        }
+
+        // The epoch must be read after `ptr` was unlinked, or garbage could
+        // be stamped with an epoch from before and freed too early
+        fence(Ordering::SeqCst);
+        let epoch = GLOBAL_EPOCH.load(Ordering::Relaxed);
+
+        let should_collect = HANDLE.with(|handle| {
+            let mut garbage = handle.garbage.borrow_mut();
+            garbage.push(Retired {
+                epoch,
+                ptr: ptr as *mut u8,
+                free: free::<T>,
+            });
+            garbage.len() >= COLLECT_THRESHOLD
+        });
+
+        if should_collect {
+            self.flush();
+        }
+    }
+
+    /// Tries to move the epoch on and free whatever garbage has expired.
+    pub fn flush(&self) {
+        HANDLE.with(|handle| handle.collect());
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        // The handle may already be gone if the guard outlives thread-local
+        // storage during thread exit, in which case it unpinned itself
+        let _ = HANDLE.try_with(|handle| {
+            let guards = handle.guards.get() - 1;
+            handle.guards.set(guards);
+
+            if guards == 0 {
+                handle.participant.epoch.store(0, Ordering::Release);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    // Other tests pin concurrently, so freeing may take a few attempts
+    fn flush_until(done: impl Fn() -> bool) {
+        for _ in 0..100_000 {
+            if done() {
+                return;
+            }
+            pin().flush();
+            std::thread::yield_now();
+        }
+        panic!("garbage was never freed");
+    }
+
+    #[test]
+    fn deferred_destruction_eventually_runs() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        {
+            let guard = pin();
+            for _ in 0..10 {
+                let ptr = Box::into_raw(Box::new(DropCounter(Arc::clone(&drops))));
+                unsafe { guard.defer_destroy(ptr) };
+            }
+            guard.flush();
+            // Still pinned, so nothing retired in this epoch can be freed yet
+            assert_eq!(drops.load(Ordering::SeqCst), 0);
+        }
+
+        flush_until(|| drops.load(Ordering::SeqCst) == 10);
+    }
+
+    #[test]
+    fn pinned_thread_holds_back_reclamation() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let (pinned_tx, pinned_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+
+        let reader = std::thread::spawn(move || {
+            let _guard = pin();
+            pinned_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        });
+        pinned_rx.recv().unwrap();
+
+        {
+            let guard = pin();
+            let ptr = Box::into_raw(Box::new(DropCounter(Arc::clone(&drops))));
+            unsafe { guard.defer_destroy(ptr) };
+        }
+        for _ in 0..100 {
+            pin().flush();
+        }
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        release_tx.send(()).unwrap();
+        reader.join().unwrap();
+        flush_until(|| drops.load(Ordering::SeqCst) == 1);
+    }
+
+    #[test]
+    fn garbage_of_exited_threads_is_adopted() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let thread_drops = Arc::clone(&drops);
+
+        std::thread::spawn(move || {
+            let guard = pin();
+            let ptr = Box::into_raw(Box::new(DropCounter(thread_drops)));
+            unsafe { guard.defer_destroy(ptr) };
+        })
+        .join()
+        .unwrap();
+
+        flush_until(|| drops.load(Ordering::SeqCst) == 1);
+    }
+
+    #[test]
+    fn nested_pins() {
+        let outer = pin();
+        let inner = pin();
+        drop(inner);
+
+        HANDLE.with(|handle| assert_eq!(handle.participant.epoch.load(Ordering::Relaxed) & 1, 1));
+        drop(outer);
+        HANDLE.with(|handle| assert_eq!(handle.participant.epoch.load(Ordering::Relaxed), 0));
+    }
+}
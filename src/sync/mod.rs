@@ -0,0 +1,16 @@
+use std::ops::Deref;
+
+pub mod epoch;
+
+// Keeps a value on its own cache line, so threads writing neighbouring
+// fields don't keep stealing the line from each other
+#[repr(align(64))]
+pub(crate) struct CachePadded<T>(pub(crate) T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}